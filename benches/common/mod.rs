@@ -0,0 +1,50 @@
+//! Setup shared by the benchmarks: a headless gameplay app and a lawn of dandelions to fill it.
+
+use bevy::prelude::*;
+
+use kill_all_dandelions::enemies::{Dandelion, DandelionSize, EnemyEntity, Species};
+use kill_all_dandelions::headless::HeadlessGameplayPlugin;
+use kill_all_dandelions::play_area::PlayArea;
+
+/// Distance between neighbouring lawn positions, far enough apart that no two dandelions up to Large merge
+pub const SPACING: f32 = 50.0;
+
+/// Headless app already in a level, with a play area that fits a lawn of `count` dandelions
+pub fn headless_app(count: usize) -> App {
+    let extent = lawn_side(count) as f32 * SPACING;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, HeadlessGameplayPlugin { seed: Some(1) }))
+        // Large enough to fit the lawn inside the grass margins
+        .insert_resource(PlayArea::new(extent * 1.5, extent * 1.5));
+
+    app.finish();
+    app.cleanup();
+    app.update();
+    app
+}
+
+/// `count` positions on a square lattice centred on the origin
+pub fn lawn(count: usize) -> impl Iterator<Item = Vec2> {
+    let side = lawn_side(count);
+    let origin = Vec2::splat(-(side as f32) * SPACING / 2.0);
+    (0..count).map(move |i| origin + Vec2::new((i % side) as f32, (i / side) as f32) * SPACING)
+}
+
+pub fn spawn_dandelion(app: &mut App, size: DandelionSize, health: u32, position: Vec2) -> Entity {
+    app.world_mut()
+        .spawn((
+            Transform::from_translation(position.extend(10.0)),
+            Dandelion {
+                health,
+                size,
+                species: Species::Dandelion,
+            },
+            EnemyEntity,
+        ))
+        .id()
+}
+
+fn lawn_side(count: usize) -> usize {
+    (count as f32).sqrt().ceil() as usize
+}
@@ -0,0 +1,31 @@
+//! Frame time of the headless gameplay app with a lawn full of dandelions.
+//!
+//! `cargo bench --bench frame_time`
+
+use bevy::prelude::*;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+use kill_all_dandelions::enemies::DandelionSize;
+
+mod common;
+
+/// Headless app with `count` Tiny dandelions, spaced so nothing merges and the count stays put between frames
+fn lawn_app(count: usize) -> App {
+    let mut app = common::headless_app(count);
+    for position in common::lawn(count) {
+        common::spawn_dandelion(&mut app, DandelionSize::Tiny, 1, position);
+    }
+    app
+}
+
+fn frame_time(c: &mut Criterion) {
+    let mut group = c.benchmark_group("frame_time");
+    for count in [1_000, 5_000] {
+        let mut app = lawn_app(count);
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, _| b.iter(|| app.update()));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, frame_time);
+criterion_main!(benches);
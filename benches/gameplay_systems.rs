@@ -0,0 +1,144 @@
+//! Hot gameplay systems at scale. Each benchmark times one headless frame in which the named
+//! system does its heavy work; `frame_time` gives the cost of the frame around it.
+//!
+//! `cargo bench --bench gameplay_systems`
+
+use bevy::prelude::*;
+use criterion::{BatchSize, BenchmarkGroup, BenchmarkId, Criterion, criterion_group, criterion_main, measurement::WallTime};
+
+use kill_all_dandelions::enemies::DandelionSize;
+use kill_all_dandelions::input::{PlayerInput, Pointer, PointerId};
+use kill_all_dandelions::powerups::{FireIgnition, Rabbit};
+
+mod common;
+
+const COUNTS: [usize; 3] = [100, 1_000, 5_000];
+
+/// Rabbits hunting the lawn in the targeting benchmark
+const RABBITS: usize = 20;
+
+/// One ignition per this many dandelions in the fire benchmark
+const DANDELIONS_PER_FIRE: usize = 20;
+
+/// Time the first frame after `setup`, on a fresh app each iteration since the frame consumes the setup
+fn bench_first_frame(group: &mut BenchmarkGroup<WallTime>, setup: fn(usize) -> App) {
+    for count in COUNTS {
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, &count| {
+            b.iter_batched(
+                || setup(count),
+                |mut app| {
+                    app.update();
+                    app
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+}
+
+/// Pairs of Tiny dandelions, each within merge radius of its partner only
+fn merging_app(count: usize) -> App {
+    let mut app = common::headless_app(count);
+    let partner_offset = Vec2::new(DandelionSize::Tiny.merge_radius() / 2.0, 0.0);
+    for position in common::lawn(count / 2) {
+        common::spawn_dandelion(&mut app, DandelionSize::Tiny, 1, position);
+        common::spawn_dandelion(&mut app, DandelionSize::Tiny, 1, position + partner_offset);
+    }
+    app
+}
+
+/// A lawn of Tiny dandelions with fires scattered across it
+fn fire_spread_app(count: usize) -> App {
+    let mut app = common::headless_app(count);
+    for (i, position) in common::lawn(count).enumerate() {
+        common::spawn_dandelion(&mut app, DandelionSize::Tiny, 1, position);
+        if i % DANDELIONS_PER_FIRE == 0 {
+            app.world_mut()
+                .spawn((Transform::from_translation(position.extend(5.0)), FireIgnition::default(), Sprite::default()));
+        }
+    }
+    app
+}
+
+/// A lawn of Tiny dandelions with rabbits that have yet to pick a target
+fn rabbit_targeting_app(count: usize) -> App {
+    let mut app = common::headless_app(count);
+    let rabbit_every = (count / RABBITS).max(1);
+    for (i, position) in common::lawn(count).enumerate() {
+        common::spawn_dandelion(&mut app, DandelionSize::Tiny, 1, position);
+        if i % rabbit_every == 0 {
+            // Between lawn positions, out of eating distance of every dandelion
+            let rabbit_position = position + Vec2::splat(common::SPACING / 2.0);
+            app.world_mut()
+                .spawn((Transform::from_translation(rabbit_position.extend(5.0)), Rabbit::default()));
+        }
+    }
+    app
+}
+
+fn merging(c: &mut Criterion) {
+    let mut group = c.benchmark_group("merging");
+    bench_first_frame(&mut group, merging_app);
+    group.finish();
+}
+
+fn fire_spread(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fire_spread");
+    bench_first_frame(&mut group, fire_spread_app);
+    group.finish();
+}
+
+fn rabbit_targeting(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rabbit_targeting");
+    bench_first_frame(&mut group, rabbit_targeting_app);
+    group.finish();
+}
+
+/// Steady state with every dandelion damaged, so each one has a health bar to manage and move
+fn health_bars(c: &mut Criterion) {
+    let mut group = c.benchmark_group("health_bars");
+    for count in COUNTS {
+        let mut app = common::headless_app(count);
+        for position in common::lawn(count) {
+            common::spawn_dandelion(&mut app, DandelionSize::Medium, 1, position);
+        }
+        // Spawn the health bars before timing
+        app.update();
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, _| b.iter(|| app.update()));
+    }
+    group.finish();
+}
+
+/// A swipe through the middle of the lawn every two frames, against dandelions too tough to die
+fn slash_hit_test(c: &mut Criterion) {
+    let mut group = c.benchmark_group("slash_hit_test");
+    for count in COUNTS {
+        let mut app = common::headless_app(count);
+        for position in common::lawn(count) {
+            common::spawn_dandelion(&mut app, DandelionSize::Tiny, u32::MAX, position);
+        }
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, _| {
+            b.iter(|| {
+                // Press on one frame, then drag across and let go on the next, which hit-tests the whole trail
+                for (position, just_pressed) in [(Vec2::new(-100.0, 0.0), true), (Vec2::new(100.0, 0.0), false)] {
+                    app.world_mut().resource_mut::<PlayerInput>().pointers = vec![Pointer {
+                        id: PointerId::Mouse,
+                        position: Some(position),
+                        just_pressed,
+                        just_released: !just_pressed,
+                    }];
+                    app.update();
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    // Fresh apps for the first-frame benchmarks are slow to build at 5,000 dandelions
+    config = Criterion::default().sample_size(20);
+    targets = merging, fire_spread, rabbit_targeting, health_bars, slash_hit_test
+}
+criterion_main!(benches);
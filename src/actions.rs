@@ -0,0 +1,379 @@
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::GameState;
+use crate::pause_menu::PauseState;
+
+/// Location of the bindings file, relative to the working directory
+const BINDINGS_FILE_PATH: &str = "save/bindings.ron";
+
+/// Bump whenever the layout of `BindingsFile` changes in a non-compatible way
+const BINDINGS_VERSION: u32 = 2;
+
+/// Plugin that maps keys and buttons to gameplay actions, with an input context per screen and player-editable bindings
+pub struct ActionsPlugin;
+
+impl Plugin for ActionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(EnhancedInputPlugin)
+            .add_input_context::<MenuInput>()
+            .add_input_context::<PlayingInput>()
+            .add_input_context::<PausedInput>()
+            .insert_resource(load_action_bindings())
+            .add_observer(bind_menu_actions)
+            .add_observer(bind_playing_actions)
+            .add_observer(bind_paused_actions)
+            .add_systems(Startup, spawn_player_actions)
+            // Swap contexts before input is evaluated, so each frame's input goes to the screen it happens on
+            .add_systems(PreUpdate, sync_input_contexts.before(EnhancedInputSystem));
+    }
+}
+
+/// Actions on the main menu
+#[derive(InputContext)]
+pub struct MenuInput;
+
+/// Actions while a level is being played
+#[derive(InputContext)]
+pub struct PlayingInput;
+
+/// Actions while the pause menu is open
+#[derive(InputContext)]
+pub struct PausedInput;
+
+// Button actions require a reset so a key held while switching screens doesn't act on the new screen
+
+/// Click, or start a swipe, at the cursor
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct Attack;
+
+/// Switch between click and slash attack modes
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct ToggleMode;
+
+/// Open the pause menu
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct Pause;
+
+/// Trigger the field powerup under the cursor without attacking
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct UsePowerup;
+
+/// Start the game from the main menu
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct Confirm;
+
+/// Leave the current menu screen
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct Back;
+
+/// Click, or start a swipe, at the gamepad reticle
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct ReticleAttack;
+
+/// Move to the next button in a direction on menu screens
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2, require_reset = true)]
+pub struct Navigate;
+
+/// Press the button focused by `Navigate`
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct Select;
+
+/// Move the gamepad reticle; a stick is never held across screens, so it needs no reset
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2)]
+pub struct MoveReticle;
+
+/// Spawn a fire at the cursor
+#[cfg(feature = "debug_keys")]
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct SpawnFire;
+
+/// Spawn rabbits at the cursor
+#[cfg(feature = "debug_keys")]
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct SpawnRabbits;
+
+/// Spray weed killer at the cursor
+#[cfg(feature = "debug_keys")]
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct SpawnWeedKiller;
+
+/// Start a lawn mower at the cursor
+#[cfg(feature = "debug_keys")]
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct SpawnLawnMower;
+
+/// Spawn geese at the cursor
+#[cfg(feature = "debug_keys")]
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct SpawnGeese;
+
+/// Spawn a ring of dandelions around the cursor
+#[cfg(feature = "debug_keys")]
+#[derive(Debug, InputAction)]
+#[input_action(output = bool, require_reset = true)]
+pub struct SpawnDandelionRing;
+
+/// Actions the player can rebind, in the order the controls screen lists them
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindableAction {
+    Attack,
+    ToggleMode,
+    Pause,
+    UsePowerup,
+    Confirm,
+    Back,
+}
+
+impl BindableAction {
+    pub const ALL: [Self; 6] = [Self::Attack, Self::ToggleMode, Self::Pause, Self::UsePowerup, Self::Confirm, Self::Back];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Attack => "Attack",
+            Self::ToggleMode => "Toggle Attack Mode",
+            Self::Pause => "Pause",
+            Self::UsePowerup => "Use Powerup",
+            Self::Confirm => "Confirm",
+            Self::Back => "Back",
+        }
+    }
+}
+
+/// Inputs bound to each action; any one of them triggers it
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActionBindings {
+    pub attack: Vec<Input>,
+    pub toggle_mode: Vec<Input>,
+    pub pause: Vec<Input>,
+    pub use_powerup: Vec<Input>,
+    pub confirm: Vec<Input>,
+    pub back: Vec<Input>,
+}
+
+impl Default for ActionBindings {
+    fn default() -> Self {
+        Self {
+            attack: vec![MouseButton::Left.into()],
+            toggle_mode: vec![KeyCode::Tab.into()],
+            pause: vec![KeyCode::KeyQ.into(), KeyCode::Escape.into()],
+            use_powerup: vec![KeyCode::KeyE.into()],
+            confirm: vec![KeyCode::Space.into(), KeyCode::Enter.into()],
+            back: vec![KeyCode::KeyQ.into(), KeyCode::Escape.into()],
+        }
+    }
+}
+
+impl ActionBindings {
+    pub fn inputs(&self, action: BindableAction) -> &Vec<Input> {
+        match action {
+            BindableAction::Attack => &self.attack,
+            BindableAction::ToggleMode => &self.toggle_mode,
+            BindableAction::Pause => &self.pause,
+            BindableAction::UsePowerup => &self.use_powerup,
+            BindableAction::Confirm => &self.confirm,
+            BindableAction::Back => &self.back,
+        }
+    }
+
+    pub fn inputs_mut(&mut self, action: BindableAction) -> &mut Vec<Input> {
+        match action {
+            BindableAction::Attack => &mut self.attack,
+            BindableAction::ToggleMode => &mut self.toggle_mode,
+            BindableAction::Pause => &mut self.pause,
+            BindableAction::UsePowerup => &mut self.use_powerup,
+            BindableAction::Confirm => &mut self.confirm,
+            BindableAction::Back => &mut self.back,
+        }
+    }
+}
+
+/// Entity holding the input context of the current screen
+#[derive(Component)]
+pub struct PlayerActions;
+
+/// Input context of each screen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ScreenContext {
+    Menu,
+    Playing,
+    Paused,
+}
+
+fn spawn_player_actions(mut commands: Commands) {
+    commands.spawn(PlayerActions);
+}
+
+/// Activate the context of the current screen and deactivate the others
+fn sync_input_contexts(
+    mut commands: Commands,
+    player_actions: Single<Entity, With<PlayerActions>>,
+    game_state: Res<State<GameState>>,
+    pause_state: Res<State<PauseState>>,
+    mut active_context: Local<Option<ScreenContext>>,
+) {
+    let context = match (game_state.get(), pause_state.get()) {
+        (GameState::Menu, _) => ScreenContext::Menu,
+        (GameState::Playing, PauseState::Playing) => ScreenContext::Playing,
+        (GameState::Playing, PauseState::Paused) => ScreenContext::Paused,
+    };
+    if *active_context == Some(context) {
+        return;
+    }
+
+    let mut entity = commands.entity(*player_actions);
+    entity.remove::<(Actions<MenuInput>, Actions<PlayingInput>, Actions<PausedInput>)>();
+    match context {
+        ScreenContext::Menu => entity.insert(Actions::<MenuInput>::default()),
+        ScreenContext::Playing => entity.insert(Actions::<PlayingInput>::default()),
+        ScreenContext::Paused => entity.insert(Actions::<PausedInput>::default()),
+    };
+    debug!("Switched input context to {:?}", context);
+    *active_context = Some(context);
+}
+
+fn bind_menu_actions(trigger: Trigger<Binding<MenuInput>>, bindings: Res<ActionBindings>, mut actions: Query<&mut Actions<MenuInput>>) {
+    let Ok(mut actions) = actions.get_mut(trigger.target()) else {
+        return;
+    };
+    actions.bind::<Confirm>().to(&bindings.confirm);
+    actions.bind::<Back>().to(&bindings.back);
+    bind_menu_navigation(&mut actions);
+}
+
+/// Menu navigation and the gamepad's back buttons are fixed and not shown on the controls screen,
+/// so rebinding a key never takes them away from controller players
+fn bind_menu_navigation<C: InputContext>(actions: &mut Actions<C>) {
+    actions.bind::<Navigate>().to((Cardinal::dpad_buttons(), Cardinal::arrow_keys()));
+    actions.bind::<Select>().to(GamepadButton::South);
+    actions.bind::<Back>().to((GamepadButton::East, GamepadButton::Start));
+}
+
+fn bind_playing_actions(trigger: Trigger<Binding<PlayingInput>>, bindings: Res<ActionBindings>, mut actions: Query<&mut Actions<PlayingInput>>) {
+    let Ok(mut actions) = actions.get_mut(trigger.target()) else {
+        return;
+    };
+    actions.bind::<Attack>().to(&bindings.attack);
+    actions.bind::<ToggleMode>().to(&bindings.toggle_mode);
+    actions.bind::<Pause>().to(&bindings.pause);
+    actions.bind::<UsePowerup>().to(&bindings.use_powerup);
+    // The gamepad is fixed to the left stick, triggers and face buttons, and not shown on the controls screen
+    actions.bind::<ToggleMode>().to(GamepadButton::West);
+    actions.bind::<Pause>().to((GamepadButton::North, GamepadButton::Start));
+    actions.bind::<MoveReticle>().to(Axial::left_stick()).with_modifiers(DeadZone::default());
+    actions.bind::<ReticleAttack>().to((GamepadButton::RightTrigger2, GamepadButton::LeftTrigger2));
+
+    // Debug keys are fixed and not shown on the controls screen
+    #[cfg(feature = "debug_keys")]
+    {
+        actions.bind::<SpawnFire>().to(KeyCode::KeyF);
+        actions.bind::<SpawnRabbits>().to(KeyCode::KeyB);
+        actions.bind::<SpawnWeedKiller>().to(KeyCode::KeyW);
+        actions.bind::<SpawnLawnMower>().to(KeyCode::KeyM);
+        actions.bind::<SpawnGeese>().to(KeyCode::KeyG);
+        actions.bind::<SpawnDandelionRing>().to(KeyCode::KeyD);
+    }
+}
+
+fn bind_paused_actions(trigger: Trigger<Binding<PausedInput>>, bindings: Res<ActionBindings>, mut actions: Query<&mut Actions<PausedInput>>) {
+    let Ok(mut actions) = actions.get_mut(trigger.target()) else {
+        return;
+    };
+    actions.bind::<Back>().to(&bindings.back);
+    bind_menu_navigation(&mut actions);
+}
+
+/// On-disk representation of the player's bindings
+#[derive(Serialize, Deserialize)]
+struct BindingsFile {
+    version: u32,
+    bindings: ActionBindings,
+}
+
+/// Load the player's bindings, falling back to the defaults when the file is missing, corrupt or from another version
+pub fn load_action_bindings() -> ActionBindings {
+    let Some(file) = read_bindings_file(Path::new(BINDINGS_FILE_PATH)) else {
+        return ActionBindings::default();
+    };
+
+    info!("Loaded input bindings from {}", BINDINGS_FILE_PATH);
+    file.bindings
+}
+
+/// Write the player's bindings to disk, replacing the previous file atomically
+pub fn save_action_bindings(bindings: &ActionBindings) {
+    if cfg!(target_arch = "wasm32") {
+        // No filesystem on the web build
+        return;
+    }
+
+    let file = BindingsFile {
+        version: BINDINGS_VERSION,
+        bindings: bindings.clone(),
+    };
+    let result = ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default())
+        .map_err(std::io::Error::other)
+        .and_then(|contents| crate::save::write_file_atomic(Path::new(BINDINGS_FILE_PATH), &contents));
+
+    match result {
+        Ok(()) => debug!("Input bindings saved to {}", BINDINGS_FILE_PATH),
+        Err(err) => warn!("Failed to save input bindings to {}: {}", BINDINGS_FILE_PATH, err),
+    }
+}
+
+/// Read and validate a bindings file, returning `None` if it can't be used
+fn read_bindings_file(path: &Path) -> Option<BindingsFile> {
+    if cfg!(target_arch = "wasm32") {
+        return None;
+    }
+
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            debug!("No bindings file at {}, using default bindings", path.display());
+            return None;
+        }
+        Err(err) => {
+            warn!("Failed to read bindings file {}: {}", path.display(), err);
+            return None;
+        }
+    };
+
+    let file: BindingsFile = match ron::from_str(&contents) {
+        Ok(file) => file,
+        Err(err) => {
+            warn!("Bindings file {} is corrupt, using default bindings: {}", path.display(), err);
+            return None;
+        }
+    };
+
+    if file.version != BINDINGS_VERSION {
+        warn!(
+            "Bindings file {} has unsupported version {} (expected {}), using default bindings",
+            path.display(),
+            file.version,
+            BINDINGS_VERSION
+        );
+        return None;
+    }
+
+    Some(file)
+}
@@ -0,0 +1,369 @@
+//! Headless level simulator for balancing.
+//!
+//! Plays each level with a bot clicker under `MinimalPlugins` and prints per-level stats:
+//!
+//! ```text
+//! cargo run --bin simulate -- [--seed N] [--cps N] [--accuracy 0..1] [--levels 1,3-5]
+//!                             [--window WxH] [--max-time SECS] [--click-mode] [--pack FILE]
+//! ```
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::IteratorRandom;
+use rand::{Rng, SeedableRng};
+use std::time::Duration;
+
+use kill_all_dandelions::GameState;
+use kill_all_dandelions::enemies::{Dandelion, DandelionMergeEvent, DandelionUpgradeEvent};
+use kill_all_dandelions::headless::HeadlessGameplayPlugin;
+use kill_all_dandelions::input::{PlayerInput, Pointer, PointerId};
+use kill_all_dandelions::level_pack::{self, LEVEL_PACK_FILE};
+use kill_all_dandelions::levels::{Level, LevelCompleteEvent, LevelData, LevelFailedEvent, LevelSession};
+use kill_all_dandelions::play_area::PlayArea;
+use kill_all_dandelions::playing::GameData;
+use kill_all_dandelions::rng::GameRng;
+
+/// Simulation settings parsed from the command line
+struct SimConfig {
+    seed: Option<u64>,
+    clicks_per_second: f32,
+    accuracy: f32,
+    levels: Option<Vec<u32>>,
+    window: Vec2,
+    max_time: Duration,
+    slash_mode: bool,
+    pack: String,
+}
+
+impl Default for SimConfig {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            clicks_per_second: 4.0,
+            accuracy: 0.8,
+            levels: None,
+            window: Vec2::new(1280.0, 720.0),
+            max_time: Duration::from_secs(600),
+            slash_mode: true,
+            pack: LEVEL_PACK_FILE.to_string(),
+        }
+    }
+}
+
+/// Length of the bot's swipes in slash mode, about two tiny dandelions across
+const BOT_SWIPE_LENGTH: f32 = 80.0;
+
+/// Margin inside the grass that dandelions spawn within, which the window must leave room for
+const DANDELION_SPAWN_MARGIN: f32 = 30.0;
+
+/// Bot that stands in for the player, clicking at a fixed rate
+#[derive(Resource)]
+struct SimBot {
+    click_timer: Timer,
+    accuracy: f32,
+    /// Seeded from the level's seed on the first click, so a run can be reproduced with `--seed`
+    rng: Option<StdRng>,
+    /// Each click is a new touch, so a swipe can still be finishing when the next click starts
+    next_touch: u64,
+    /// Touch to release on the next frame and where its swipe ends
+    swipe_end: Option<(u64, Vec2)>,
+}
+
+/// Counters collected while a level is simulated
+#[derive(Resource, Default)]
+struct SimStats {
+    clicks: u32,
+    peak_dandelions: usize,
+    merges: u32,
+    upgrades: u32,
+    /// Roots that grew back into dandelions
+    regrown: u32,
+    outcome: Option<SimOutcome>,
+}
+
+/// How a simulated level ended
+enum SimOutcome {
+    Completed { time: Duration, score: u32, stars: u32 },
+    Failed { time: Duration, score: u32 },
+}
+
+/// Result of simulating one level
+struct LevelReport {
+    level_id: u32,
+    seed: u64,
+    stats: SimStats,
+}
+
+fn main() -> AppExit {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let config = match parse_args(&args) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{}", err);
+            return AppExit::error();
+        }
+    };
+
+    let levels = match level_pack::read_level_pack(&config.pack) {
+        Ok(pack) => pack.levels,
+        Err(err) => {
+            eprintln!("Failed to load level pack {}, using built-in levels: {}", config.pack, err);
+            LevelData::default().levels
+        }
+    };
+
+    let level_ids: Vec<u32> = match &config.levels {
+        Some(ids) => ids.clone(),
+        None => levels.iter().map(|level| level.id).collect(),
+    };
+
+    println!(
+        "Simulating {} level(s) at {:.1} clicks/s, {:.0}% accuracy, {} mode, {}x{} play area",
+        level_ids.len(),
+        config.clicks_per_second,
+        config.accuracy * 100.0,
+        if config.slash_mode { "slash" } else { "click" },
+        config.window.x,
+        config.window.y
+    );
+    println!();
+    println!(
+        "{:>5}  {:>20}  {:<9}  {:>8}  {:>5}  {:>6}  {:>6}  {:>4}  {:>6}  {:>8}  {:>7}",
+        "level", "seed", "result", "time", "stars", "score", "clicks", "peak", "merges", "upgrades", "regrown"
+    );
+
+    for level_id in level_ids {
+        let Some(level) = levels.iter().find(|level| level.id == level_id) else {
+            eprintln!("Level {} is not in the level pack, skipping", level_id);
+            continue;
+        };
+
+        print_report(&simulate_level(&config, &levels, level));
+    }
+
+    AppExit::Success
+}
+
+/// Play one level to completion, failure or `max_time` in a fresh headless app
+fn simulate_level(config: &SimConfig, levels: &[Level], level: &Level) -> LevelReport {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, HeadlessGameplayPlugin { seed: config.seed }))
+        .insert_resource(GameData {
+            slash_mode: config.slash_mode,
+            ..GameData::new()
+        })
+        .insert_resource(PlayArea::new(config.window.x, config.window.y))
+        .insert_resource(SimBot {
+            click_timer: Timer::from_seconds(1.0 / config.clicks_per_second, TimerMode::Repeating),
+            accuracy: config.accuracy,
+            rng: None,
+            next_touch: 0,
+            swipe_end: None,
+        })
+        .init_resource::<SimStats>()
+        .add_systems(PreUpdate, run_bot.run_if(in_state(GameState::Playing)))
+        .add_systems(PostUpdate, (track_peak_dandelions, record_outcome))
+        .add_observer(|_trigger: Trigger<DandelionMergeEvent>, mut stats: ResMut<SimStats>| stats.merges += 1)
+        .add_observer(|_trigger: Trigger<DandelionUpgradeEvent>, mut stats: ResMut<SimStats>| stats.upgrades += 1);
+
+    // Unlock checks don't apply: every level is played on its own
+    let mut level_data = app.world_mut().resource_mut::<LevelData>();
+    level_data.replace_levels(levels.to_vec());
+    level_data.current_level = level.id;
+
+    app.finish();
+    app.cleanup();
+
+    loop {
+        app.update();
+
+        let elapsed = app.world().resource::<LevelSession>().get_elapsed_time();
+        if app.world().resource::<SimStats>().outcome.is_some() || elapsed >= config.max_time {
+            break;
+        }
+    }
+
+    LevelReport {
+        level_id: level.id,
+        seed: app.world().resource::<GameRng>().seed(),
+        stats: app.world_mut().remove_resource::<SimStats>().unwrap_or_default(),
+    }
+}
+
+/// Click at the configured rate, aiming at a random dandelion `accuracy` of the time.
+/// In slash mode each click is a short swipe out from its target, released on the following frame.
+fn run_bot(
+    mut bot: ResMut<SimBot>,
+    mut player_input: ResMut<PlayerInput>,
+    mut stats: ResMut<SimStats>,
+    time: Res<Time>,
+    game_rng: Res<GameRng>,
+    play_area: Res<PlayArea>,
+    dandelions: Query<&Transform, With<Dandelion>>,
+) {
+    *player_input = PlayerInput::default();
+
+    if let Some((touch, end)) = bot.swipe_end.take() {
+        player_input.pointers.push(Pointer {
+            id: PointerId::Touch(touch),
+            position: Some(end),
+            just_pressed: false,
+            just_released: true,
+        });
+    }
+
+    bot.click_timer.tick(time.delta());
+    if !bot.click_timer.just_finished() {
+        return;
+    }
+
+    let accuracy = bot.accuracy;
+    let rng = bot.rng.get_or_insert_with(|| StdRng::seed_from_u64(game_rng.seed()));
+
+    let target = if rng.gen_bool(accuracy as f64) {
+        dandelions.iter().choose(rng).map(|transform| transform.translation.truncate())
+    } else {
+        None
+    };
+
+    let press = target.unwrap_or_else(|| {
+        let bounds = play_area.grass_bounds(0.0);
+        Vec2::new(rng.gen_range(bounds.min.x..bounds.max.x), rng.gen_range(bounds.min.y..bounds.max.y))
+    });
+
+    let swipe = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * BOT_SWIPE_LENGTH;
+    let touch = bot.next_touch;
+    bot.next_touch += 1;
+    bot.swipe_end = Some((touch, press + swipe));
+    player_input.pointers.push(Pointer {
+        id: PointerId::Touch(touch),
+        position: Some(press),
+        just_pressed: true,
+        just_released: false,
+    });
+    stats.clicks += 1;
+}
+
+/// Track the largest number of dandelions alive at once
+fn track_peak_dandelions(mut stats: ResMut<SimStats>, dandelions: Query<(), With<Dandelion>>) {
+    stats.peak_dandelions = stats.peak_dandelions.max(dandelions.iter().count());
+}
+
+/// Stop the simulation once the level is completed or failed
+fn record_outcome(
+    mut stats: ResMut<SimStats>,
+    mut level_complete_events: EventReader<LevelCompleteEvent>,
+    mut level_failed_events: EventReader<LevelFailedEvent>,
+    level_session: Res<LevelSession>,
+    game_data: Res<GameData>,
+) {
+    stats.regrown = level_session.roots_regrown;
+
+    for event in level_complete_events.read() {
+        stats.outcome = Some(SimOutcome::Completed {
+            time: event.completion_time,
+            score: event.final_score,
+            stars: event.stars_earned,
+        });
+    }
+
+    if level_failed_events.read().count() > 0 {
+        stats.outcome = Some(SimOutcome::Failed {
+            time: level_session.get_elapsed_time(),
+            score: game_data.score,
+        });
+    }
+}
+
+fn print_report(report: &LevelReport) {
+    let stats = &report.stats;
+    let (result, time, stars, score) = match stats.outcome {
+        Some(SimOutcome::Completed { time, score, stars }) => ("completed", time, stars.to_string(), score),
+        Some(SimOutcome::Failed { time, score }) => ("timed out", time, "-".to_string(), score),
+        None => ("gave up", Duration::ZERO, "-".to_string(), 0),
+    };
+
+    println!(
+        "{:>5}  {:>20}  {:<9}  {:>7.2}s  {:>5}  {:>6}  {:>6}  {:>4}  {:>6}  {:>8}  {:>7}",
+        report.level_id,
+        report.seed,
+        result,
+        time.as_secs_f32(),
+        stars,
+        score,
+        stats.clicks,
+        stats.peak_dandelions,
+        stats.merges,
+        stats.upgrades,
+        stats.regrown
+    );
+}
+
+fn parse_args(args: &[String]) -> Result<SimConfig, String> {
+    let mut config = SimConfig::default();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+
+        match arg.as_str() {
+            "--seed" => config.seed = Some(parse_value(arg, value()?)?),
+            "--cps" => config.clicks_per_second = parse_value(arg, value()?)?,
+            "--accuracy" => config.accuracy = parse_value(arg, value()?)?,
+            "--levels" => config.levels = Some(parse_level_list(value()?)?),
+            "--window" => config.window = parse_window_size(value()?)?,
+            "--max-time" => {
+                config.max_time =
+                    Duration::try_from_secs_f32(parse_value(arg, value()?)?).map_err(|_| "--max-time must be a non-negative number of seconds".to_string())?
+            }
+            "--pack" => config.pack = value()?.clone(),
+            "--click-mode" => config.slash_mode = false,
+            _ => return Err(format!("unknown argument `{}`", arg)),
+        }
+    }
+
+    if !config.clicks_per_second.is_finite() || config.clicks_per_second <= 0.0 {
+        return Err("--cps must be positive".to_string());
+    }
+    if !(0.0..=1.0).contains(&config.accuracy) {
+        return Err("--accuracy must be between 0 and 1".to_string());
+    }
+
+    Ok(config)
+}
+
+fn parse_value<T: std::str::FromStr>(arg: &str, value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("invalid value `{}` for {}", value, arg))
+}
+
+/// Parse a level list like `1,3-5`
+fn parse_level_list(value: &str) -> Result<Vec<u32>, String> {
+    let mut levels = Vec::new();
+    for part in value.split(',') {
+        match part.split_once('-') {
+            Some((first, last)) => {
+                let first: u32 = parse_value("--levels", first)?;
+                let last: u32 = parse_value("--levels", last)?;
+                levels.extend(first..=last);
+            }
+            None => levels.push(parse_value("--levels", part)?),
+        }
+    }
+    Ok(levels)
+}
+
+/// Parse a size like `1280x720`
+fn parse_window_size(value: &str) -> Result<Vec2, String> {
+    let (width, height) = value
+        .split_once('x')
+        .ok_or_else(|| format!("invalid window size `{}`, expected WIDTHxHEIGHT", value))?;
+    let size = Vec2::new(parse_value("--window", width)?, parse_value("--window", height)?);
+    if !size.is_finite() || size.min_element() <= 0.0 {
+        return Err(format!("invalid window size `{}`", value));
+    }
+    let grass = PlayArea::new(size.x, size.y).grass_bounds(0.0);
+    if grass.size().min_element() <= 2.0 * DANDELION_SPAWN_MARGIN {
+        return Err(format!("window size `{}` leaves no room for dandelions to spawn", value));
+    }
+    Ok(size)
+}
@@ -0,0 +1,213 @@
+use bevy::prelude::*;
+
+use crate::GameState;
+use crate::levels::LevelStartEvent;
+use crate::pause_menu::PauseState;
+use crate::powerups::{FireIgnition, PoisonZone, PowerupType};
+
+/// How long a powerup's trace lingers where it acted
+const TRACE_LIFETIME: f32 = 2.0;
+/// How close to a trace another powerup must start to combo with it
+const TRACE_RADIUS: f32 = 60.0;
+
+/// Plugin for interaction rules between powerups, driven by `POWERUP_COMBOS`
+pub struct CombosPlugin;
+
+impl Plugin for CombosPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PowerupAreas>()
+            .init_resource::<PowerupTraces>()
+            .add_systems(OnEnter(GameState::Playing), reset_combo_resources)
+            .add_systems(
+                PreUpdate,
+                reset_combo_resources.run_if(in_state(GameState::Playing)).run_if(on_event::<LevelStartEvent>),
+            )
+            .add_systems(
+                Update,
+                (
+                    collect_powerup_areas.in_set(CollectPowerupAreas),
+                    extinguish_powerups.after(CollectPowerupAreas),
+                    age_powerup_traces,
+                )
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(in_state(PauseState::Playing)),
+            );
+    }
+}
+
+/// System set that rebuilds `PowerupAreas`; systems reading it should run after this
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CollectPowerupAreas;
+
+/// What happens when `actor` meets `other`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComboEffect {
+    /// `actor` units run out of `other`'s area
+    Flee,
+    /// `other` started on a recent `actor` trace spreads this many more generations
+    ExtraGenerations(u32),
+    /// `actor`'s area puts out any `other` inside it
+    Extinguish,
+}
+
+/// One interaction rule between two powerup types
+#[derive(Clone, Copy, Debug)]
+pub struct PowerupCombo {
+    pub actor: PowerupType,
+    pub other: PowerupType,
+    pub effect: ComboEffect,
+}
+
+/// Every powerup interaction; new powerup types declare theirs here
+pub const POWERUP_COMBOS: &[PowerupCombo] = &[
+    PowerupCombo {
+        actor: PowerupType::Bunny,
+        other: PowerupType::Flamethrower,
+        effect: ComboEffect::Flee,
+    },
+    PowerupCombo {
+        actor: PowerupType::Bunny,
+        other: PowerupType::Flamethrower,
+        effect: ComboEffect::ExtraGenerations(1),
+    },
+    PowerupCombo {
+        actor: PowerupType::WeedKiller,
+        other: PowerupType::Flamethrower,
+        effect: ComboEffect::Extinguish,
+    },
+];
+
+/// A circular area a powerup is affecting this frame
+#[derive(Clone, Copy, Debug)]
+pub struct PowerupArea {
+    pub entity: Entity,
+    pub powerup_type: PowerupType,
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+/// Areas of every active area powerup, rebuilt each frame
+#[derive(Resource, Default)]
+pub struct PowerupAreas {
+    areas: Vec<PowerupArea>,
+}
+
+impl PowerupAreas {
+    /// Center of the nearest area that `actor` units at `point` should run from
+    pub fn threat_to(&self, actor: PowerupType, point: Vec2) -> Option<Vec2> {
+        POWERUP_COMBOS
+            .iter()
+            .filter(|combo| combo.actor == actor && combo.effect == ComboEffect::Flee)
+            .flat_map(|combo| self.areas.iter().filter(move |area| area.powerup_type == combo.other))
+            .filter(|area| area.center.distance(point) <= area.radius)
+            .map(|area| area.center)
+            .min_by(|a, b| a.distance_squared(point).total_cmp(&b.distance_squared(point)))
+    }
+
+    /// Whether any `powerup_type` area covers `point`
+    pub fn covers(&self, powerup_type: PowerupType, point: Vec2) -> bool {
+        self.areas
+            .iter()
+            .any(|area| area.powerup_type == powerup_type && area.center.distance(point) <= area.radius)
+    }
+}
+
+/// A mark left where a powerup recently acted
+#[derive(Clone, Debug)]
+struct PowerupTrace {
+    powerup_type: PowerupType,
+    position: Vec2,
+    lifetime: Timer,
+}
+
+/// Recent powerup traces that later powerups can combo with
+#[derive(Resource, Default)]
+pub struct PowerupTraces {
+    traces: Vec<PowerupTrace>,
+}
+
+impl PowerupTraces {
+    /// Leave a trace of `powerup_type` at `position`
+    pub fn leave(&mut self, powerup_type: PowerupType, position: Vec2) {
+        self.traces.push(PowerupTrace {
+            powerup_type,
+            position,
+            lifetime: Timer::from_seconds(TRACE_LIFETIME, TimerMode::Once),
+        });
+    }
+
+    /// Extra generations an `other` powerup started at `position` gets from nearby traces
+    pub fn extra_generations(&self, other: PowerupType, position: Vec2) -> u32 {
+        POWERUP_COMBOS
+            .iter()
+            .filter(|combo| combo.other == other)
+            .filter_map(|combo| match combo.effect {
+                ComboEffect::ExtraGenerations(extra) => Some((combo.actor, extra)),
+                _ => None,
+            })
+            .filter(|(actor, _)| {
+                self.traces
+                    .iter()
+                    .any(|trace| trace.powerup_type == *actor && trace.position.distance(position) <= TRACE_RADIUS)
+            })
+            .map(|(_, extra)| extra)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Clear areas and traces left over from the previous level
+fn reset_combo_resources(mut areas: ResMut<PowerupAreas>, mut traces: ResMut<PowerupTraces>) {
+    areas.areas.clear();
+    traces.traces.clear();
+}
+
+/// Rebuild the list of active powerup areas
+fn collect_powerup_areas(
+    mut areas: ResMut<PowerupAreas>,
+    fire_query: Query<(Entity, &Transform, &FireIgnition)>,
+    poison_query: Query<(Entity, &Transform, &PoisonZone)>,
+) {
+    areas.areas.clear();
+    for (entity, transform, fire) in fire_query.iter() {
+        areas.areas.push(PowerupArea {
+            entity,
+            powerup_type: PowerupType::Flamethrower,
+            center: transform.translation.truncate(),
+            radius: fire.radius(),
+        });
+    }
+    for (entity, transform, zone) in poison_query.iter() {
+        areas.areas.push(PowerupArea {
+            entity,
+            powerup_type: PowerupType::WeedKiller,
+            center: transform.translation.truncate(),
+            radius: zone.radius(),
+        });
+    }
+}
+
+/// Put out powerups that are inside an area that extinguishes them
+fn extinguish_powerups(mut commands: Commands, areas: Res<PowerupAreas>) {
+    for combo in POWERUP_COMBOS.iter().filter(|combo| combo.effect == ComboEffect::Extinguish) {
+        for actor_area in areas.areas.iter().filter(|area| area.powerup_type == combo.actor) {
+            for other_area in areas.areas.iter().filter(|area| area.powerup_type == combo.other) {
+                if other_area.center.distance(actor_area.center) <= actor_area.radius {
+                    commands.entity(other_area.entity).try_despawn();
+                    debug!(
+                        "{:?} put out {:?} at ({:.1}, {:.1})",
+                        combo.actor, combo.other, other_area.center.x, other_area.center.y
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Let traces fade away
+fn age_powerup_traces(mut traces: ResMut<PowerupTraces>, time: Res<Time>) {
+    for trace in traces.traces.iter_mut() {
+        trace.lifetime.tick(time.delta());
+    }
+    traces.traces.retain(|trace| !trace.lifetime.finished());
+}
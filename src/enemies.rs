@@ -1,20 +1,28 @@
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use rand::Rng;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
-#[cfg(feature = "debug_keys")]
 use crate::GameAssets;
 use crate::GameState;
-use crate::levels::LevelData;
+use crate::combos::{CollectPowerupAreas, PowerupAreas};
+use crate::input::{PlayerInput, PointerId};
+use crate::levels::{LevelData, LevelSession, LevelStartEvent, level_not_failed};
 use crate::pause_menu::PauseState;
+use crate::play_area::PlayArea;
 use crate::playing::GameData;
+use crate::powerups::{PoisonZone, PowerupType};
+use crate::rng::GameRng;
+use crate::spatial_grid::SpatialGrid;
+use crate::wind::Wind;
 
-/// Event triggered when a dandelion dies
+/// Event triggered when a dandelion dies, targeting the dying dandelion
 #[derive(Event)]
 pub struct DandelionDeathEvent {
     pub position: Vec2,
     pub size: DandelionSize,
+    pub species: Species,
 }
 
 /// Event triggered when two dandelions merge into a larger one
@@ -65,23 +73,37 @@ pub struct EnemiesPlugin;
 
 impl Plugin for EnemiesPlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(on_dandelion_death)
+        app.init_resource::<DandelionGrid>()
+            .add_observer(on_dandelion_death)
             .add_observer(on_dandelion_merge)
             .add_observer(on_dandelion_upgrade)
             .add_observer(on_seed_spawn)
             .add_observer(on_sound_effect)
-            .add_systems(OnEnter(GameState::Playing), (setup_enemy_timer, setup_area_tracker, setup_variety_spawner))
+            .add_systems(
+                OnEnter(GameState::Playing),
+                (setup_enemy_timer, setup_area_tracker, setup_variety_spawner, setup_slash_trails),
+            )
+            .add_systems(
+                PreUpdate,
+                (setup_enemy_timer, setup_area_tracker, setup_variety_spawner, setup_slash_trails)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(on_event::<LevelStartEvent>),
+            )
             .add_systems(
                 Update,
                 (
-                    spawn_dandelions,
-                    spawn_variety_dandelions,
-                    handle_dandelion_clicks,
-                    update_seed_orbs,
-                    check_dandelion_merging,
+                    spawn_dandelions.run_if(level_not_failed),
+                    spawn_variety_dandelions.run_if(level_not_failed),
+                    handle_dandelion_clicks.after(RebuildDandelionGrid),
+                    update_seed_orbs.in_set(MoveSeedOrbs),
+                    spread_clover.run_if(level_not_failed).after(RebuildDandelionGrid),
+                    grow_dandelions.run_if(level_not_failed),
+                    update_roots.run_if(level_not_failed).after(CollectPowerupAreas).after(handle_dandelion_clicks),
+                    check_dandelion_merging.after(RebuildDandelionGrid),
                     update_merge_effects,
                     update_moving_dandelions,
-                    check_moving_dandelion_collisions,
+                    rebuild_dandelion_grid.in_set(RebuildDandelionGrid).after(update_moving_dandelions),
+                    check_moving_dandelion_collisions.after(RebuildDandelionGrid),
                     update_upgrade_cooldowns,
                     manage_health_bars,
                     update_health_bar_positions,
@@ -131,13 +153,197 @@ impl Default for VarietySpawnTimer {
 #[derive(Resource, Default)]
 pub struct DandelionAreaTracker {
     pub total_area: f32,
+    /// Area of the dandelions that live roots will regrow into
+    pub root_area: f32,
 }
 
-/// Component marking dandelion enemies
+/// Spatial index of every dandelion for proximity queries, rebuilt each frame once dandelions have moved
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct DandelionGrid(pub SpatialGrid);
+
+/// Path of a slash being swiped, from the press to wherever its click or touch has dragged since
+pub struct SlashTrail {
+    points: Vec<Vec2>,
+    length: f32,
+}
+
+impl SlashTrail {
+    /// Shortest pointer movement that adds a point to the trail
+    const MIN_STEP: f32 = 5.0;
+
+    /// Start a new trail at the press position
+    fn new(position: Vec2) -> Self {
+        Self {
+            points: vec![position],
+            length: 0.0,
+        }
+    }
+
+    pub fn points(&self) -> &[Vec2] {
+        &self.points
+    }
+
+    /// Follow the pointer to `position`, cutting the trail off once it is `max_length` long
+    fn extend_to(&mut self, position: Vec2, max_length: f32) {
+        let Some(&last) = self.points.last() else {
+            return;
+        };
+        let step = last.distance(position);
+        let remaining = max_length - self.length;
+        if step < Self::MIN_STEP || remaining <= 0.0 {
+            return;
+        }
+
+        let step = step.min(remaining);
+        self.points.push(last + (position - last).normalize() * step);
+        self.length += step;
+    }
+}
+
+/// Slashes being swiped, one per held click or touch
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct SlashTrails(HashMap<PointerId, SlashTrail>);
+
+/// System set that rebuilds `DandelionGrid`; systems querying it should run after this
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RebuildDandelionGrid;
+
+/// System set that moves seed orbs and lands them; systems catching seeds in flight should run before this
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MoveSeedOrbs;
+
+/// Component marking weed enemies, dandelions or any other species
 #[derive(Component, Clone)]
 pub struct Dandelion {
     pub health: u32,
     pub size: DandelionSize,
+    pub species: Species,
+}
+
+/// Weed species, each with its own way of spreading
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Species {
+    /// Scatters seeds when killed
+    #[default]
+    Dandelion,
+    /// Sends out runners while alive, and dies without spreading
+    Clover,
+    /// Breaks the combo when clicked, but can be slashed safely
+    Thistle,
+}
+
+impl Species {
+    /// Points for a kill before the combo multiplier
+    pub fn points(self) -> u32 {
+        match self {
+            Species::Dandelion => 10,
+            // Worth more, since every second it lives it spreads
+            Species::Clover => 15,
+            Species::Thistle => 20,
+        }
+    }
+
+    /// How much a weed hurts curb appeal compared to a dandelion of the same size
+    pub fn curb_appeal_weight(self) -> f32 {
+        match self {
+            Species::Dandelion => 1.0,
+            // Low growing, so a single patch is barely noticed until it takes over
+            Species::Clover => 0.5,
+            // Tall and spiky, it stands out more than anything else on the lawn
+            Species::Thistle => 1.5,
+        }
+    }
+
+    /// Get the preloaded image for a weed of this species at `size`
+    pub fn image(self, size: DandelionSize, assets: &GameAssets) -> Handle<Image> {
+        match self {
+            Species::Dandelion => size.image(assets),
+            Species::Clover => assets.clover.clone(),
+            Species::Thistle => match size {
+                DandelionSize::Tiny | DandelionSize::Small => assets.thistle_young.clone(),
+                DandelionSize::Medium | DandelionSize::Large | DandelionSize::Huge => assets.thistle_flowering.clone(),
+            },
+        }
+    }
+}
+
+/// Clover patch that roots a new patch next to itself on a timer
+#[derive(Component)]
+pub struct CloverRunners {
+    timer: Timer,
+}
+
+impl CloverRunners {
+    /// Seconds between runners
+    const INTERVAL: f32 = 4.0;
+    /// How far from its parent a runner roots
+    const LENGTH: f32 = 36.0;
+    /// Runners don't root this close to another weed
+    const SPACING: f32 = 30.0;
+    /// Clover stops spreading once the lawn has this many patches
+    const MAX_PATCHES: usize = 60;
+}
+
+impl Default for CloverRunners {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(Self::INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Yellow flower stage of a dandelion; it goes to seed as a puffball when the timer is up
+#[derive(Component)]
+pub struct Flower {
+    timer: Timer,
+}
+
+impl Flower {
+    /// Seconds a dandelion flowers before turning into a puffball
+    const BLOOM_TIME: f32 = 6.0;
+}
+
+impl Default for Flower {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(Self::BLOOM_TIME, TimerMode::Once),
+        }
+    }
+}
+
+/// Puffball stage of a dandelion; the wind carries its seeds off if it is left alone until the timer is up
+#[derive(Component)]
+pub struct Puffball {
+    timer: Timer,
+}
+
+impl Puffball {
+    /// Seconds a puffball holds on to its seeds
+    const DISPERSE_TIME: f32 = 4.0;
+}
+
+impl Default for Puffball {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(Self::DISPERSE_TIME, TimerMode::Once),
+        }
+    }
+}
+
+/// What a killed dandelion leaves in the ground on levels with roots; it regrows unless dug up or burned
+#[derive(Component)]
+pub struct Root {
+    regrow: Timer,
+    cleared: bool,
+}
+
+impl Root {
+    pub fn new(regrow_time: Duration) -> Self {
+        Self {
+            regrow: Timer::new(regrow_time, TimerMode::Once),
+            cleared: false,
+        }
+    }
 }
 
 /// Dandelion size variants
@@ -151,14 +357,25 @@ pub enum DandelionSize {
 }
 
 impl DandelionSize {
-    /// Get the asset filename for this size
-    pub fn asset_path(&self) -> &'static str {
+    /// Get the preloaded image for this size
+    pub fn image(&self, assets: &GameAssets) -> Handle<Image> {
         match self {
-            DandelionSize::Tiny => "dandelion_tiny.png",
-            DandelionSize::Small => "dandelion_small.png",
-            DandelionSize::Medium => "dandelion_medium.png",
-            DandelionSize::Large => "dandelion_large.png",
-            DandelionSize::Huge => "dandelion_huge.png",
+            DandelionSize::Tiny => assets.dandelion_tiny.clone(),
+            DandelionSize::Small => assets.dandelion_small.clone(),
+            DandelionSize::Medium => assets.dandelion_medium.clone(),
+            DandelionSize::Large => assets.dandelion_large.clone(),
+            DandelionSize::Huge => assets.dandelion_huge.clone(),
+        }
+    }
+
+    /// Get the preloaded puffball image for this size
+    pub fn puffball_image(&self, assets: &GameAssets) -> Handle<Image> {
+        match self {
+            DandelionSize::Tiny => assets.dandelion_tiny_puffball.clone(),
+            DandelionSize::Small => assets.dandelion_small_puffball.clone(),
+            DandelionSize::Medium => assets.dandelion_medium_puffball.clone(),
+            DandelionSize::Large => assets.dandelion_large_puffball.clone(),
+            DandelionSize::Huge => assets.dandelion_huge_puffball.clone(),
         }
     }
 
@@ -196,6 +413,11 @@ impl DandelionSize {
         }
     }
 
+    /// Largest collision radius of any size, for finding every dandelion that could touch a point
+    pub fn max_collision_radius() -> f32 {
+        DandelionSize::Huge.collision_radius()
+    }
+
     /// Get merge radius (when dandelions should combine)
     pub fn merge_radius(&self) -> f32 {
         self.collision_radius() * 1.2
@@ -267,9 +489,8 @@ pub struct MovingDandelion {
     direction_change_timer: Timer,
 }
 
-impl Default for MovingDandelion {
-    fn default() -> Self {
-        let mut rng = rand::thread_rng();
+impl MovingDandelion {
+    pub fn new(rng: &mut impl Rng) -> Self {
         let angle = rng.gen_range(0.0..std::f32::consts::TAU);
         let speed = 50.0;
         Self {
@@ -324,16 +545,22 @@ fn setup_variety_spawner(mut commands: Commands) {
     commands.insert_resource(VarietySpawnTimer::default());
 }
 
+/// Drop any slashes left over from a previous level
+fn setup_slash_trails(mut commands: Commands) {
+    commands.insert_resource(SlashTrails::default());
+}
+
 /// Spawn dandelions at random positions
 fn spawn_dandelions(
     mut commands: Commands,
     mut spawn_timer: ResMut<DandelionSpawnTimer>,
     time: Res<Time>,
-    windows: Query<&Window>,
-    asset_server: Res<AssetServer>,
+    play_area: Res<PlayArea>,
+    game_assets: Res<GameAssets>,
     mut game_data: ResMut<GameData>,
     mut area_tracker: ResMut<DandelionAreaTracker>,
     level_data: Option<Res<LevelData>>,
+    mut rng: ResMut<GameRng>,
 ) {
     // Apply level-based spawn rate scaling
     let spawn_rate_multiplier = if let Some(level_data) = &level_data {
@@ -351,50 +578,127 @@ fn spawn_dandelions(
     spawn_timer.timer.tick(adjusted_delta);
 
     if spawn_timer.timer.just_finished() {
-        if let Ok(window) = windows.single() {
-            let mut rng = rand::thread_rng();
+        // Spawn on the grass, clear of the UI panels at top and bottom
+        let bounds = play_area.grass_bounds(30.0);
+
+        let x = rng.gen_range(bounds.min.x..bounds.max.x);
+        let y = rng.gen_range(bounds.min.y..bounds.max.y);
+
+        // Only roll for other species on levels that have them, so other levels keep their layouts
+        let scaling = level_data
+            .as_ref()
+            .and_then(|level_data| level_data.levels.get((level_data.current_level - 1) as usize))
+            .map(|level| &level.enemy_scaling);
+        let clover_chance = scaling.map_or(0.0, |scaling| scaling.clover_chance);
+        let thistle_chance = scaling.map_or(0.0, |scaling| scaling.thistle_chance);
+        let species = if clover_chance > 0.0 && rng.gen_bool(clover_chance.min(1.0) as f64) {
+            Species::Clover
+        } else if thistle_chance > 0.0 && rng.gen_bool(thistle_chance.min(1.0) as f64) {
+            Species::Thistle
+        } else {
+            Species::Dandelion
+        };
+
+        // Thistles come up already past the seedling stage
+        let size = match species {
+            Species::Thistle => DandelionSize::Small,
+            Species::Dandelion | Species::Clover => DandelionSize::Tiny,
+        };
 
-            // Calculate safe spawn area to avoid UI panels at top and bottom
-            let margin = 30.0; // Margin from edges
-            let top_ui_height = window.height() * 0.12; // 12vh for top panel
-            let bottom_ui_height = window.height() * 0.08; // 8vh for bottom panel
+        // Apply level-based health scaling
+        let health = calculate_max_health(size, species, level_data.as_deref());
 
-            // Calculate available grass area
-            let min_x = -window.width() / 2.0 + margin;
-            let max_x = window.width() / 2.0 - margin;
-            let min_y = -window.height() / 2.0 + bottom_ui_height + margin;
-            let max_y = window.height() / 2.0 - top_ui_height - margin;
+        match species {
+            Species::Dandelion => {
+                commands.spawn((
+                    Sprite {
+                        image: species.image(size, &game_assets),
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                    Transform::from_translation(Vec3::new(x, y, 10.0)).with_scale(Vec3::splat(size.scale())),
+                    Dandelion { health, size, species },
+                    Flower::default(),
+                    EnemyEntity,
+                ));
+            }
+            Species::Thistle => {
+                commands.spawn((
+                    Sprite {
+                        image: species.image(size, &game_assets),
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                    Transform::from_translation(Vec3::new(x, y, 10.0)).with_scale(Vec3::splat(size.scale())),
+                    Dandelion { health, size, species },
+                    EnemyEntity,
+                ));
+            }
+            Species::Clover => spawn_clover(&mut commands, &game_assets, Vec2::new(x, y), health),
+        }
 
-            let x = rng.gen_range(min_x..max_x);
-            let y = rng.gen_range(min_y..max_y);
+        game_data.dandelion_count += 1;
+        area_tracker.total_area += size.visual_area();
+    }
+}
 
-            // Apply level-based health scaling
-            let base_health = 1;
-            let health = if let Some(level_data) = &level_data {
-                if let Some(current_level) = level_data.levels.get((level_data.current_level - 1) as usize) {
-                    (base_health as f32 * current_level.enemy_scaling.health_multiplier).ceil() as u32
-                } else {
-                    base_health
-                }
-            } else {
-                base_health
-            };
+/// Spawn a clover patch, which keeps sending out runners while it is alive
+pub fn spawn_clover(commands: &mut Commands, assets: &GameAssets, position: Vec2, health: u32) {
+    let size = DandelionSize::Tiny;
+    commands.spawn((
+        Sprite {
+            image: assets.clover.clone(),
+            color: Color::WHITE,
+            ..default()
+        },
+        Transform::from_translation(position.extend(10.0)).with_scale(Vec3::splat(size.scale())),
+        Dandelion {
+            health,
+            size,
+            species: Species::Clover,
+        },
+        CloverRunners::default(),
+        EnemyEntity,
+    ));
+}
 
-            let size = DandelionSize::Tiny;
-            commands.spawn((
-                Sprite {
-                    image: asset_server.load(size.asset_path()),
-                    color: Color::WHITE,
-                    ..default()
-                },
-                Transform::from_translation(Vec3::new(x, y, 10.0)).with_scale(Vec3::splat(size.scale())),
-                Dandelion { health, size },
-                EnemyEntity,
-            ));
+/// Root a new clover patch beside each patch whose runner timer is up
+fn spread_clover(
+    mut commands: Commands,
+    mut clover_query: Query<(&Transform, &mut CloverRunners)>,
+    weed_query: Query<&Dandelion>,
+    time: Res<Time>,
+    play_area: Res<PlayArea>,
+    game_assets: Res<GameAssets>,
+    mut game_data: ResMut<GameData>,
+    mut area_tracker: ResMut<DandelionAreaTracker>,
+    level_data: Option<Res<LevelData>>,
+    mut rng: ResMut<GameRng>,
+    grid: Res<DandelionGrid>,
+) {
+    let mut patches = weed_query.iter().filter(|weed| weed.species == Species::Clover).count();
+    let bounds = play_area.grass_bounds(30.0);
+    let health = calculate_max_health(DandelionSize::Tiny, Species::Clover, level_data.as_deref());
 
-            game_data.dandelion_count += 1;
-            area_tracker.total_area += size.visual_area();
+    for (transform, mut runners) in clover_query.iter_mut() {
+        runners.timer.tick(time.delta());
+        if !runners.timer.just_finished() || patches >= CloverRunners::MAX_PATCHES {
+            continue;
+        }
+
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let position = transform.translation.truncate() + Vec2::from_angle(angle) * CloverRunners::LENGTH;
+
+        // Runners stay on the lawn and never root on top of another weed
+        if !bounds.contains(position) || grid.query_radius(position, CloverRunners::SPACING).next().is_some() {
+            continue;
         }
+
+        spawn_clover(&mut commands, &game_assets, position, health);
+        patches += 1;
+        game_data.dandelion_count += 1;
+        area_tracker.total_area += DandelionSize::Tiny.visual_area();
+        debug!("Clover runner rooted at ({:.1}, {:.1})", position.x, position.y);
     }
 }
 
@@ -407,157 +711,294 @@ struct DandelionGameState<'w, 's> {
     _game_assets: Res<'w, crate::GameAssets>,
 }
 
-/// Handle clicks and touches on dandelions
+/// Handle clicks and touches on dandelions: a press hits in click mode, a swipe from press to release slashes in slash mode.
+/// Every click and touch is handled on its own, so several fingers hit or slash at once.
 fn handle_dandelion_clicks(
-    game_state: DandelionGameState,
-    dandelion_query: Query<(Entity, &mut Dandelion, &Transform)>,
-    mouse_input: Res<ButtonInput<MouseButton>>,
-    touches: Res<Touches>,
-    windows: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
+    mut game_state: DandelionGameState,
+    mut dandelion_query: Query<(Entity, &mut Dandelion, &Transform)>,
+    player_input: Res<PlayerInput>,
     level_data: Option<Res<LevelData>>,
+    grid: Res<DandelionGrid>,
+    mut slash_trails: ResMut<SlashTrails>,
+    mut root_query: Query<(Entity, &Transform, &mut Root)>,
+    mut level_session: Option<ResMut<LevelSession>>,
 ) {
-    // Check for mouse click
-    let mouse_clicked = mouse_input.just_pressed(MouseButton::Left);
-
-    // Check for touch input (any finger touching)
-    let touch_started = touches.any_just_pressed();
-
-    if !mouse_clicked && !touch_started {
+    if !game_state.game_data.slash_mode {
+        // Drop swipes cut short by switching modes
+        if !slash_trails.is_empty() {
+            slash_trails.clear();
+        }
+        for world_pos in player_input.presses() {
+            debug!("Click/touch at world position: ({:.1}, {:.1})", world_pos.x, world_pos.y);
+            // A click that misses every dandelion can still dig up a root
+            if !process_dandelion_hit(&mut game_state, &mut dandelion_query, &grid, world_pos) {
+                dig_up_roots(&mut game_state, &mut root_query, level_session.as_deref_mut(), &[world_pos]);
+            }
+        }
         return;
     }
 
-    // Get position from mouse or touch
-    let world_pos = if mouse_clicked {
-        get_world_click_position(&windows, &camera_query)
-    } else if touch_started {
-        get_world_touch_position(&windows, &camera_query, &touches)
-    } else {
-        None
-    };
+    let total_stars = level_data.as_ref().map(|ld| ld.get_total_stars()).unwrap_or(0);
 
-    let world_pos = match world_pos {
-        Some(pos) => pos,
-        None => return,
-    };
+    // Check if extended slash is unlocked (15+ stars) - makes trails twice as long
+    let base_slash_length = game_state.game_data.slash_length;
+    let slash_length = if total_stars >= 15 { base_slash_length * 2.0 } else { base_slash_length };
 
-    debug!("Click/touch at world position: ({:.1}, {:.1})", world_pos.x, world_pos.y);
+    // Drop swipes whose click or touch went away without being let go
+    if slash_trails.keys().any(|id| !player_input.pointers.iter().any(|pointer| pointer.id == *id)) {
+        slash_trails.retain(|id, _| player_input.pointers.iter().any(|pointer| pointer.id == *id));
+    }
 
-    // Check if using slash mode or regular click mode
-    if game_state.game_data.slash_mode {
-        process_slash_attack(game_state, dandelion_query, world_pos, level_data);
-    } else {
-        process_dandelion_hit(game_state, dandelion_query, world_pos);
+    for pointer in &player_input.pointers {
+        if let (true, Some(world_pos)) = (pointer.just_pressed, pointer.position) {
+            slash_trails.insert(pointer.id, SlashTrail::new(world_pos));
+        }
+        if let (Some(trail), Some(world_pos)) = (slash_trails.get_mut(&pointer.id), pointer.position) {
+            trail.extend_to(world_pos, slash_length);
+        }
+        if pointer.just_released
+            && let Some(trail) = slash_trails.remove(&pointer.id)
+        {
+            dig_up_roots(&mut game_state, &mut root_query, level_session.as_deref_mut(), trail.points());
+            process_slash_attack(&mut game_state, &mut dandelion_query, &grid, trail.points, total_stars);
+        }
     }
 }
 
-/// Convert screen click to world coordinates
-fn get_world_click_position(windows: &Query<&Window>, camera_query: &Query<(&Camera, &GlobalTransform)>) -> Option<Vec2> {
-    let window = windows.single().ok()?;
-    let (camera, camera_transform) = camera_query.single().ok()?;
-    let cursor_pos = window.cursor_position()?;
-    camera.viewport_to_world_2d(camera_transform, cursor_pos).ok()
+/// Check if click hit a dandelion and process the hit, returning whether it did
+fn process_dandelion_hit(
+    game_state: &mut DandelionGameState,
+    dandelion_query: &mut Query<(Entity, &mut Dandelion, &Transform)>,
+    grid: &DandelionGrid,
+    click_pos: Vec2,
+) -> bool {
+    for (entity, _) in grid.query_radius(click_pos, DandelionSize::max_collision_radius()) {
+        let Ok((entity, mut dandelion, transform)) = dandelion_query.get_mut(entity) else {
+            continue;
+        };
+        let dandelion_pos = transform.translation.truncate();
+        let collision_radius = dandelion.size.collision_radius();
+        let distance = click_pos.distance(dandelion_pos);
+
+        // Skip dandelions already destroyed by another touch this frame
+        if distance <= collision_radius && dandelion.health > 0 {
+            // Grabbing a thistle hurts, so only a slash clears one without losing the combo
+            if dandelion.species == Species::Thistle {
+                debug!("Clicked a thistle, losing a {}x combo", game_state.game_data.combo);
+                game_state.game_data.reset_combo();
+            }
+            damage_dandelion(game_state, entity, &mut dandelion, dandelion_pos);
+            return true; // Only hit one dandelion per click
+        }
+    }
+    false
 }
 
-/// Convert touch position to world coordinates
-fn get_world_touch_position(windows: &Query<&Window>, camera_query: &Query<(&Camera, &GlobalTransform)>, touches: &Touches) -> Option<Vec2> {
-    let _window = windows.single().ok()?;
-    let (camera, camera_transform) = camera_query.single().ok()?;
+/// Turn flowers into puffballs, and let the wind carry off the seeds of puffballs left alone
+fn grow_dandelions(
+    mut commands: Commands,
+    mut flower_query: Query<(Entity, &Dandelion, &mut Flower, Option<&mut Sprite>), Without<Puffball>>,
+    mut puffball_query: Query<(Entity, &Transform, &Dandelion, &mut Puffball, Option<&mut Sprite>), Without<Flower>>,
+    time: Res<Time>,
+    game_assets: Res<GameAssets>,
+    mut rng: ResMut<GameRng>,
+) {
+    for (entity, dandelion, mut flower, sprite) in flower_query.iter_mut() {
+        flower.timer.tick(time.delta());
+        if flower.timer.just_finished() {
+            if let Some(mut sprite) = sprite {
+                sprite.image = dandelion.size.puffball_image(&game_assets);
+            }
+            commands.entity(entity).try_remove::<Flower>().try_insert(Puffball::default());
+        }
+    }
+
+    for (entity, transform, dandelion, mut puffball, sprite) in puffball_query.iter_mut() {
+        puffball.timer.tick(time.delta());
+        if !puffball.timer.just_finished() {
+            continue;
+        }
 
-    // Get the first touch that just started
-    let touch = touches.iter_just_pressed().next()?;
-    let touch_pos = touch.position();
+        let position = transform.translation.truncate();
+        let spawn_count = dandelion.size.spawn_count();
+        spawn_seed_orbs(&mut commands, &game_assets, &mut *rng, position, spawn_count);
 
-    camera.viewport_to_world_2d(camera_transform, touch_pos).ok()
+        // With its seeds gone it flowers again
+        if let Some(mut sprite) = sprite {
+            sprite.image = dandelion.size.image(&game_assets);
+        }
+        commands.entity(entity).try_remove::<Puffball>().try_insert(Flower::default());
+        debug!("Wind carried {} seeds off a puffball at ({:.1}, {:.1})", spawn_count, position.x, position.y);
+    }
 }
 
-/// Check if click hit a dandelion and process the hit
-fn process_dandelion_hit(mut game_state: DandelionGameState, mut dandelion_query: Query<(Entity, &mut Dandelion, &Transform)>, click_pos: Vec2) {
-    for (entity, mut dandelion, transform) in dandelion_query.iter_mut() {
-        let dandelion_pos = transform.translation.truncate();
-        let collision_radius = dandelion.size.collision_radius();
-        let distance = click_pos.distance(dandelion_pos);
+/// Dig up every root a click or slash `trail` touches
+fn dig_up_roots(
+    game_state: &mut DandelionGameState,
+    root_query: &mut Query<(Entity, &Transform, &mut Root)>,
+    mut level_session: Option<&mut LevelSession>,
+    trail: &[Vec2],
+) {
+    let reach = DandelionSize::Tiny.collision_radius();
+    for (entity, transform, mut root) in root_query.iter_mut() {
+        let position = transform.translation.truncate();
+        let touched = match trail {
+            [point] => point.distance(position) <= reach,
+            _ => trail
+                .windows(2)
+                .any(|segment| distance_point_to_line_segment(position, segment[0], segment[1]) <= reach),
+        };
 
-        if distance <= collision_radius {
-            damage_dandelion(&mut game_state, entity, &mut dandelion, dandelion_pos);
-            break; // Only hit one dandelion per click
+        // Skip roots already dug up by another touch this frame
+        if touched && !root.cleared {
+            clear_root(
+                &mut game_state.commands,
+                &mut game_state.area_tracker,
+                level_session.as_deref_mut(),
+                entity,
+                &mut root,
+            );
+            debug!("Root dug up at ({:.1}, {:.1})", position.x, position.y);
         }
     }
 }
 
-/// Process slash attack hitting all dandelions along a diagonal line
-fn process_slash_attack(
-    mut game_state: DandelionGameState,
-    mut dandelion_query: Query<(Entity, &mut Dandelion, &Transform)>,
-    click_pos: Vec2,
+/// Remove a root before it regrows
+fn clear_root(commands: &mut Commands, area_tracker: &mut DandelionAreaTracker, level_session: Option<&mut LevelSession>, entity: Entity, root: &mut Root) {
+    root.cleared = true;
+    commands.entity(entity).despawn();
+    area_tracker.root_area -= DandelionSize::Tiny.visual_area();
+    if let Some(level_session) = level_session {
+        level_session.roots_cleared += 1;
+    }
+}
+
+/// Burn roots caught in fire, and regrow the rest into Tiny dandelions once their time is up
+fn update_roots(
+    mut commands: Commands,
+    mut root_query: Query<(Entity, &Transform, &mut Root)>,
+    time: Res<Time>,
+    powerup_areas: Res<PowerupAreas>,
+    mut area_tracker: ResMut<DandelionAreaTracker>,
+    mut level_session: Option<ResMut<LevelSession>>,
     level_data: Option<Res<LevelData>>,
 ) {
-    let base_slash_offset = game_state.game_data.slash_offset;
-    let total_stars = level_data.as_ref().map(|ld| ld.get_total_stars()).unwrap_or(0);
+    for (entity, transform, mut root) in root_query.iter_mut() {
+        if root.cleared {
+            continue;
+        }
+        let position = transform.translation.truncate();
 
-    // Check if double slash is unlocked (9+ stars)
-    let is_double_slash = total_stars >= 9;
+        if powerup_areas.covers(PowerupType::Flamethrower, position) {
+            clear_root(&mut commands, &mut area_tracker, level_session.as_deref_mut(), entity, &mut root);
+            debug!("Root burned at ({:.1}, {:.1})", position.x, position.y);
+            continue;
+        }
+
+        root.regrow.tick(time.delta());
+        if !root.regrow.finished() {
+            continue;
+        }
 
-    // Check if extended slash is unlocked (15+ stars) - makes slashes twice as long
-    let slash_offset = if total_stars >= 15 { base_slash_offset * 2.0 } else { base_slash_offset };
+        root.cleared = true;
+        commands.entity(entity).despawn();
+        area_tracker.root_area -= DandelionSize::Tiny.visual_area();
+        if let Some(level_session) = level_session.as_deref_mut() {
+            level_session.roots_regrown += 1;
+        }
 
-    // First slash: diagonal from top-right to bottom-left
-    let start_pos1 = click_pos + Vec2::new(slash_offset, slash_offset);
-    let end_pos1 = click_pos - Vec2::new(slash_offset, slash_offset);
+        // The root sprouts just like a landed seed
+        commands.trigger(SeedSpawnEvent {
+            position,
+            size: DandelionSize::Tiny,
+            health: calculate_max_health(DandelionSize::Tiny, Species::Dandelion, level_data.as_deref()),
+        });
+        debug!("Root regrew at ({:.1}, {:.1})", position.x, position.y);
+    }
+}
 
-    // Spawn visual slash effect for first slash
-    crate::playing::spawn_slash_effect(&mut game_state.commands, start_pos1, end_pos1);
+/// Process slash attack hitting all dandelions along a swiped trail
+fn process_slash_attack(
+    game_state: &mut DandelionGameState,
+    dandelion_query: &mut Query<(Entity, &mut Dandelion, &Transform)>,
+    grid: &DandelionGrid,
+    trail: Vec<Vec2>,
+    total_stars: u32,
+) {
+    // Check if double slash is unlocked (9+ stars) - the trail strikes a second time
+    let is_double_slash = total_stars >= 9;
 
-    let mut total_hit_count = 0;
+    // Spawn visual slash effect for first strike
+    crate::playing::spawn_slash_effect(&mut game_state.commands, &trail);
 
-    // Process first slash
-    for (entity, mut dandelion, transform) in dandelion_query.iter_mut() {
+    let hits = dandelions_on_trail(grid, dandelion_query, &trail);
+    for &entity in &hits {
+        let Ok((entity, mut dandelion, transform)) = dandelion_query.get_mut(entity) else {
+            continue;
+        };
         let dandelion_pos = transform.translation.truncate();
-        let collision_radius = dandelion.size.collision_radius();
-
-        // Calculate distance from dandelion to first slash line
-        let distance_to_line = distance_point_to_line_segment(dandelion_pos, start_pos1, end_pos1);
+        damage_dandelion(game_state, entity, &mut dandelion, dandelion_pos);
+    }
 
-        if distance_to_line <= collision_radius {
-            damage_dandelion(&mut game_state, entity, &mut dandelion, dandelion_pos);
-            total_hit_count += 1;
-        }
+    let slash_type = match (total_stars >= 15, is_double_slash) {
+        (true, true) => "Extended double",
+        (false, true) => "Double",
+        (true, false) => "Extended single",
+        (false, false) => "Single",
+    };
+    if !hits.is_empty() {
+        debug!(
+            "{} slash attack hit {} dandelions along a {}-point trail (stars: {})",
+            slash_type,
+            hits.len(),
+            trail.len(),
+            total_stars
+        );
     }
 
-    // If double slash is unlocked, perform second slash
+    // If double slash is unlocked, strike the same trail again after a slight delay (0.1 seconds)
     if is_double_slash {
-        // Second slash: diagonal from top-left to bottom-right (reverse diagonal)
-        let start_pos2 = click_pos + Vec2::new(-slash_offset, slash_offset);
-        let end_pos2 = click_pos - Vec2::new(-slash_offset, slash_offset);
-
-        // Spawn visual slash effect for second slash with slight delay (0.1 seconds)
-        crate::playing::spawn_delayed_slash_effect(&mut game_state.commands, start_pos2, end_pos2, 0.1);
-
-        // Process second slash
-        for (entity, mut dandelion, transform) in dandelion_query.iter_mut() {
-            let dandelion_pos = transform.translation.truncate();
-            let collision_radius = dandelion.size.collision_radius();
+        crate::playing::spawn_delayed_slash_effect(&mut game_state.commands, trail, 0.1);
+    }
+}
 
-            // Calculate distance from dandelion to second slash line
-            let distance_to_line = distance_point_to_line_segment(dandelion_pos, start_pos2, end_pos2);
+/// Dandelions touched by a slash `trail`, each once, in the order the trail reaches them
+fn dandelions_on_trail(grid: &DandelionGrid, dandelion_query: &Query<(Entity, &mut Dandelion, &Transform)>, trail: &[Vec2]) -> Vec<Entity> {
+    // A tap leaves a single point, which slashes like a zero length segment
+    let segments = trail.windows(2).map(|segment| (segment[0], segment[1]));
+    let tap = (trail.len() == 1).then(|| (trail[0], trail[0]));
+
+    let mut hit = HashSet::new();
+    let mut hits = Vec::new();
+    for (start, end) in segments.chain(tap) {
+        for entity in dandelions_near_segment(grid, start, end) {
+            if hit.contains(&entity) {
+                continue;
+            }
+            let Ok((_, dandelion, transform)) = dandelion_query.get(entity) else {
+                continue;
+            };
 
-            if distance_to_line <= collision_radius {
-                damage_dandelion(&mut game_state, entity, &mut dandelion, dandelion_pos);
-                total_hit_count += 1;
+            // Skip dandelions already destroyed by another slash this frame
+            if dandelion.health == 0 {
+                continue;
             }
-        }
 
-        if total_hit_count > 0 {
-            let slash_type = if total_stars >= 15 { "Extended double" } else { "Double" };
-            debug!("{} slash attack hit {} dandelions total (stars: {})", slash_type, total_hit_count, total_stars);
+            // Calculate distance from dandelion to this segment of the trail
+            let distance_to_line = distance_point_to_line_segment(transform.translation.truncate(), start, end);
+            if distance_to_line <= dandelion.size.collision_radius() {
+                hit.insert(entity);
+                hits.push(entity);
+            }
         }
-    } else if total_hit_count > 0 {
-        let slash_type = if total_stars >= 15 { "Extended single" } else { "Single" };
-        debug!(
-            "{} slash attack hit {} dandelions along line from ({:.1}, {:.1}) to ({:.1}, {:.1})",
-            slash_type, total_hit_count, start_pos1.x, start_pos1.y, end_pos1.x, end_pos1.y
-        );
     }
+    hits
+}
+
+/// Dandelions that could be touching the segment from `start` to `end`
+fn dandelions_near_segment(grid: &DandelionGrid, start: Vec2, end: Vec2) -> impl Iterator<Item = Entity> + '_ {
+    let reach = Rect::from_corners(start, end).inflate(DandelionSize::max_collision_radius());
+    grid.query_rect(reach).map(|(entity, _)| entity)
 }
 
 /// Calculate distance from a point to a line segment
@@ -583,54 +1024,97 @@ pub fn process_delayed_slash_damage(
     game_data: &mut ResMut<GameData>,
     game_assets: &Res<crate::GameAssets>,
     dandelion_query: &mut Query<(Entity, &mut Dandelion, &Transform)>,
-    slash_start: Vec2,
-    slash_end: Vec2,
+    grid: &DandelionGrid,
+    trail: &[Vec2],
 ) -> u32 {
     let mut hit_count = 0;
 
-    for (entity, mut dandelion, transform) in dandelion_query.iter_mut() {
+    for entity in dandelions_on_trail(grid, dandelion_query, trail) {
+        let Ok((entity, mut dandelion, transform)) = dandelion_query.get_mut(entity) else {
+            continue;
+        };
         let dandelion_pos = transform.translation.truncate();
-        let collision_radius = dandelion.size.collision_radius();
-
-        // Calculate distance from dandelion to delayed slash line
-        let distance_to_line = distance_point_to_line_segment(dandelion_pos, slash_start, slash_end);
 
-        if distance_to_line <= collision_radius {
-            // Apply damage manually (similar to damage_dandelion but without creating the game state)
-            dandelion.health = dandelion.health.saturating_sub(1);
+        // Apply damage manually (similar to damage_dandelion but without creating the game state)
+        dandelion.health = dandelion.health.saturating_sub(1);
 
-            // Play slash sound effect when dandelion is hit
-            play_slash_sound(commands, game_assets);
+        // Play slash sound effect when dandelion is hit
+        play_slash_sound(commands, game_assets);
 
-            if dandelion.health == 0 {
-                // Trigger death event for seed spawning
-                commands.trigger(DandelionDeathEvent {
+        if dandelion.health == 0 {
+            // Trigger death event for seed spawning
+            commands.trigger_targets(
+                DandelionDeathEvent {
                     position: dandelion_pos,
                     size: dandelion.size,
-                });
+                    species: dandelion.species,
+                },
+                entity,
+            );
 
-                // Handle destruction without the full game state
-                game_data.add_dandelion_kill();
+            // Handle destruction without the full game state
+            game_data.add_kill(dandelion.species);
 
-                // Despawn the dandelion
-                if let Ok(mut ec) = commands.get_entity(entity) {
-                    ec.despawn();
-                }
+            // Despawn the dandelion
+            if let Ok(mut ec) = commands.get_entity(entity) {
+                ec.despawn();
             }
-
-            hit_count += 1;
         }
+
+        hit_count += 1;
     }
 
     hit_count
 }
 
-/// Observer that handles dandelion death events and spawns seeds
-fn on_dandelion_death(trigger: Trigger<DandelionDeathEvent>, mut commands: Commands, asset_server: Res<AssetServer>) {
+/// Observer that handles dandelion death events and spawns seeds, plus a root on levels with roots
+fn on_dandelion_death(
+    trigger: Trigger<DandelionDeathEvent>,
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    mut rng: ResMut<GameRng>,
+    level_data: Option<Res<LevelData>>,
+    mut area_tracker: ResMut<DandelionAreaTracker>,
+    mut level_session: Option<ResMut<LevelSession>>,
+    flower_query: Query<(), With<Flower>>,
+) {
     let event = trigger.event();
+    // Only dandelions scatter seeds; clover spreads by runners and thistles not at all
+    if event.species != Species::Dandelion {
+        return;
+    }
+
+    let root_regrow_time = level_data
+        .as_ref()
+        .and_then(|level_data| level_data.get_current_level())
+        .and_then(|level| level.root_regrow_time);
+    if let Some(regrow_time) = root_regrow_time {
+        let size = DandelionSize::Tiny;
+        commands.spawn((
+            Sprite {
+                image: game_assets.root.clone(),
+                color: Color::WHITE,
+                ..default()
+            },
+            // Below the dandelions, so one growing on top hides it
+            Transform::from_translation(event.position.extend(5.0)).with_scale(Vec3::splat(size.scale())),
+            Root::new(regrow_time),
+            EnemyEntity,
+        ));
+        area_tracker.root_area += size.visual_area();
+        if let Some(level_session) = level_session.as_deref_mut() {
+            level_session.roots_left += 1;
+        }
+    }
+
+    // Flowers haven't gone to seed yet
+    if flower_query.contains(trigger.target()) {
+        debug!("Dandelion killed in flower at ({:.1}, {:.1}), no seeds", event.position.x, event.position.y);
+        return;
+    }
     let spawn_count = event.size.spawn_count();
 
-    spawn_seed_orbs(&mut commands, &asset_server, event.position, spawn_count);
+    spawn_seed_orbs(&mut commands, &game_assets, &mut *rng, event.position, spawn_count);
 
     debug!(
         "Dandelion death observer: spawning {} seeds at ({:.1}, {:.1})",
@@ -642,10 +1126,11 @@ fn on_dandelion_death(trigger: Trigger<DandelionDeathEvent>, mut commands: Comma
 fn on_dandelion_merge(
     trigger: Trigger<DandelionMergeEvent>,
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
     mut game_data: ResMut<GameData>,
     mut area_tracker: ResMut<DandelionAreaTracker>,
     level_data: Option<Res<LevelData>>,
+    mut rng: ResMut<GameRng>,
 ) {
     let event = trigger.event();
 
@@ -686,18 +1171,23 @@ fn on_dandelion_merge(
     // Create new merged dandelion
     let mut entity_commands = commands.spawn((
         Sprite {
-            image: asset_server.load(event.new_size.asset_path()),
+            image: event.new_size.image(&game_assets),
             color: Color::WHITE,
             ..default()
         },
         Transform::from_translation(Vec3::new(event.position.x, event.position.y, 10.0)).with_scale(Vec3::splat(event.new_size.scale())),
-        Dandelion { health, size: event.new_size },
+        Dandelion {
+            health,
+            size: event.new_size,
+            species: Species::Dandelion,
+        },
+        Flower::default(),
         EnemyEntity,
     ));
 
     // Add moving component if huge size
     if event.new_size == DandelionSize::Huge {
-        entity_commands.insert(MovingDandelion::default());
+        entity_commands.insert(MovingDandelion::new(&mut *rng));
     }
 
     // Update count (2 removed, 1 added = net -1)
@@ -719,8 +1209,9 @@ fn on_dandelion_merge(
 fn on_dandelion_upgrade(
     trigger: Trigger<DandelionUpgradeEvent>,
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
     mut area_tracker: ResMut<DandelionAreaTracker>,
+    mut rng: ResMut<GameRng>,
 ) {
     let event = trigger.event();
 
@@ -733,22 +1224,23 @@ fn on_dandelion_upgrade(
         entity_commands.try_insert(UpgradeCooldown::default());
     }
 
-    // Update the sprite and transform
+    // Update the sprite and transform, and start the bigger dandelion flowering afresh
     if let Ok(mut entity_commands) = commands.get_entity(event.stationary_entity) {
-        entity_commands.try_insert((
+        entity_commands.try_remove::<Puffball>().try_insert((
             Sprite {
-                image: asset_server.load(event.new_size.asset_path()),
+                image: event.new_size.image(&game_assets),
                 color: Color::WHITE,
                 ..default()
             },
             Transform::from_translation(Vec3::new(event.position.x, event.position.y, 10.0)).with_scale(Vec3::splat(event.new_size.scale())),
+            Flower::default(),
         ));
     }
 
     // If it became huge, make it moving too
     if event.new_size == DandelionSize::Huge {
         if let Ok(mut entity_commands) = commands.get_entity(event.stationary_entity) {
-            entity_commands.try_insert(MovingDandelion::default());
+            entity_commands.try_insert(MovingDandelion::new(&mut *rng));
         }
     }
 
@@ -768,7 +1260,7 @@ fn on_dandelion_upgrade(
 fn on_seed_spawn(
     trigger: Trigger<SeedSpawnEvent>,
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
     mut game_data: ResMut<GameData>,
     mut area_tracker: ResMut<DandelionAreaTracker>,
 ) {
@@ -776,7 +1268,7 @@ fn on_seed_spawn(
 
     commands.spawn((
         Sprite {
-            image: asset_server.load(event.size.asset_path()),
+            image: event.size.image(&game_assets),
             color: Color::WHITE,
             ..default()
         },
@@ -784,7 +1276,9 @@ fn on_seed_spawn(
         Dandelion {
             health: event.health,
             size: event.size,
+            species: Species::Dandelion,
         },
+        Flower::default(),
         EnemyEntity,
     ));
 
@@ -838,15 +1332,19 @@ fn damage_dandelion(game_state: &mut DandelionGameState, entity: Entity, dandeli
 
     if dandelion.health == 0 {
         // Trigger death event for seed spawning
-        game_state.commands.trigger(DandelionDeathEvent {
-            position,
-            size: dandelion.size,
-        });
+        game_state.commands.trigger_targets(
+            DandelionDeathEvent {
+                position,
+                size: dandelion.size,
+                species: dandelion.species,
+            },
+            entity,
+        );
 
         // Handle the rest of destruction immediately
         game_state.area_tracker.total_area -= dandelion.size.visual_area();
         game_state.commands.entity(entity).despawn();
-        game_state.game_data.add_dandelion_kill();
+        game_state.game_data.add_kill(dandelion.species);
         game_state.game_data.dandelion_count = game_state.game_data.dandelion_count.saturating_sub(1);
 
         debug!(
@@ -896,9 +1394,7 @@ fn cleanup_enemies(mut commands: Commands, enemy_entities: Query<Entity, With<En
 }
 
 /// Spawn seed orbs that will create new dandelions after a delay
-fn spawn_seed_orbs(commands: &mut Commands, asset_server: &Res<AssetServer>, origin: Vec2, count: u32) {
-    let mut rng = rand::thread_rng();
-
+fn spawn_seed_orbs(commands: &mut Commands, assets: &GameAssets, rng: &mut impl Rng, origin: Vec2, count: u32) {
     for _ in 0..count {
         // Generate random direction and distance for seed travel
         let angle = rng.gen_range(0.0..std::f32::consts::TAU);
@@ -908,7 +1404,7 @@ fn spawn_seed_orbs(commands: &mut Commands, asset_server: &Res<AssetServer>, ori
 
         commands.spawn((
             Sprite {
-                image: asset_server.load("seed.png"),
+                image: assets.seed.clone(),
                 color: Color::WHITE,
                 ..default()
             },
@@ -926,15 +1422,25 @@ fn spawn_seed_orbs(commands: &mut Commands, asset_server: &Res<AssetServer>, ori
     }
 }
 
-/// Update seed orb movement and spawning
-fn update_seed_orbs(mut commands: Commands, mut orb_query: Query<(Entity, &mut Transform, &mut SeedOrb)>, time: Res<Time>, level_data: Option<Res<LevelData>>) {
+/// Update seed orb movement and spawning, unless the seed lands in a poison zone
+fn update_seed_orbs(
+    mut commands: Commands,
+    mut orb_query: Query<(Entity, &mut Transform, &mut SeedOrb)>,
+    poison_zones: Query<(&Transform, &PoisonZone), Without<SeedOrb>>,
+    time: Res<Time>,
+    level_data: Option<Res<LevelData>>,
+    wind: Res<Wind>,
+) {
     for (entity, mut transform, mut orb) in orb_query.iter_mut() {
         orb.spawn_timer.tick(time.delta());
 
-        // Move orb toward target position
+        // The wind carries seeds downwind while they fly
+        orb.target_position += wind.velocity() * time.delta_secs();
+
+        // Move orb toward target position, faster with the wind behind it
         let current_pos = transform.translation.truncate();
         let direction = (orb.target_position - current_pos).normalize_or_zero();
-        let move_speed = 150.0;
+        let move_speed = (150.0 + wind.velocity().dot(direction)).max(50.0);
         let new_pos = current_pos + direction * move_speed * time.delta_secs();
         transform.translation = Vec3::new(new_pos.x, new_pos.y, 15.0);
 
@@ -952,6 +1458,14 @@ fn update_seed_orbs(mut commands: Commands, mut orb_query: Query<(Entity, &mut T
                 ec.despawn();
             }
 
+            let poisoned = poison_zones
+                .iter()
+                .any(|(zone_transform, zone)| zone.covers(zone_transform.translation.truncate(), orb.target_position));
+            if poisoned {
+                debug!("Seed orb landed in weed killer at ({:.1}, {:.1})", orb.target_position.x, orb.target_position.y);
+                continue;
+            }
+
             // Apply level-based health scaling
             let base_health = 1;
             let health = if let Some(level_data) = &level_data {
@@ -977,52 +1491,49 @@ fn update_seed_orbs(mut commands: Commands, mut orb_query: Query<(Entity, &mut T
 }
 
 /// Check for dandelions that should merge together
-fn check_dandelion_merging(mut commands: Commands, dandelion_query: Query<(Entity, &Dandelion, &Transform)>) {
+fn check_dandelion_merging(mut commands: Commands, dandelion_query: Query<(Entity, &Dandelion, &Transform)>, grid: Res<DandelionGrid>) {
     let mut to_merge: Vec<(Entity, Entity, Vec2, DandelionSize, DandelionSize)> = Vec::new();
     let mut entities_to_remove: HashSet<Entity> = HashSet::new();
 
-    // Collect all dandelions for comparison
-    let dandelions: Vec<(Entity, &Dandelion, &Transform)> = dandelion_query.iter().collect();
+    for (entity1, dandelion1, transform1) in dandelion_query.iter() {
+        // Skip if already marked for removal, or a species that doesn't merge
+        if entities_to_remove.contains(&entity1) || dandelion1.species != Species::Dandelion {
+            continue;
+        }
 
-    // Check all pairs for merging opportunities
-    for i in 0..dandelions.len() {
-        for j in (i + 1)..dandelions.len() {
-            let (entity1, dandelion1, transform1) = dandelions[i];
-            let (entity2, dandelion2, transform2) = dandelions[j];
+        let pos1 = transform1.translation.truncate();
+        let merge_radius = dandelion1.size.merge_radius();
 
-            // Skip if either entity is already marked for removal
-            if entities_to_remove.contains(&entity1) || entities_to_remove.contains(&entity2) {
-                continue;
+        // Find a nearby dandelion of the same size that isn't already merging
+        let partner = grid.query_radius(pos1, merge_radius).find_map(|(entity2, _)| {
+            if entity2 == entity1 || entities_to_remove.contains(&entity2) {
+                return None;
             }
+            dandelion_query
+                .get(entity2)
+                .ok()
+                .filter(|(_, dandelion2, _)| dandelion2.size == dandelion1.size && dandelion2.species == dandelion1.species)
+        });
+        let Some((entity2, _, transform2)) = partner else {
+            continue;
+        };
+        let pos2 = transform2.translation.truncate();
 
-            // Only merge dandelions of the same size
-            if dandelion1.size != dandelion2.size {
-                continue;
-            }
+        // Check if we can create a larger dandelion
+        if let Some(new_size) = dandelion1.size.next_size() {
+            // Calculate merge position (midpoint)
+            let merge_pos = (pos1 + pos2) / 2.0;
 
-            let pos1 = transform1.translation.truncate();
-            let pos2 = transform2.translation.truncate();
-            let distance = pos1.distance(pos2);
-            let merge_radius = dandelion1.size.merge_radius();
-
-            if distance <= merge_radius {
-                // Check if we can create a larger dandelion
-                if let Some(new_size) = dandelion1.size.next_size() {
-                    // Calculate merge position (midpoint)
-                    let merge_pos = (pos1 + pos2) / 2.0;
-
-                    to_merge.push((entity1, entity2, merge_pos, new_size, dandelion1.size));
-                    entities_to_remove.insert(entity1);
-                    entities_to_remove.insert(entity2);
-
-                    debug!(
-                        "Merging two {:?} dandelions at ({:.1}, {:.1}) and ({:.1}, {:.1}) into {:?} at ({:.1}, {:.1})",
-                        dandelion1.size, pos1.x, pos1.y, pos2.x, pos2.y, new_size, merge_pos.x, merge_pos.y
-                    );
-                } else {
-                    debug!("Two {:?} dandelions are close but cannot merge further (already at max size)", dandelion1.size);
-                }
-            }
+            to_merge.push((entity1, entity2, merge_pos, new_size, dandelion1.size));
+            entities_to_remove.insert(entity1);
+            entities_to_remove.insert(entity2);
+
+            debug!(
+                "Merging two {:?} dandelions at ({:.1}, {:.1}) and ({:.1}, {:.1}) into {:?} at ({:.1}, {:.1})",
+                dandelion1.size, pos1.x, pos1.y, pos2.x, pos2.y, new_size, merge_pos.x, merge_pos.y
+            );
+        } else {
+            debug!("Two {:?} dandelions are close but cannot merge further (already at max size)", dandelion1.size);
         }
     }
 
@@ -1077,46 +1588,47 @@ fn update_merge_effects(mut commands: Commands, mut effect_query: Query<(Entity,
 }
 
 /// Update moving dandelions
-fn update_moving_dandelions(mut moving_query: Query<(&mut Transform, &mut MovingDandelion)>, time: Res<Time>, windows: Query<&Window>) {
-    if let Ok(window) = windows.single() {
-        let margin = 50.0;
-        let top_ui_height = window.height() * 0.12;
-        let bottom_ui_height = window.height() * 0.08;
-
-        let bounds = Rect::new(
-            -window.width() / 2.0 + margin,
-            -window.height() / 2.0 + bottom_ui_height + margin,
-            window.width() / 2.0 - margin,
-            window.height() / 2.0 - top_ui_height - margin,
-        );
-
-        for (mut transform, mut moving) in moving_query.iter_mut() {
-            moving.direction_change_timer.tick(time.delta());
+fn update_moving_dandelions(
+    mut moving_query: Query<(&mut Transform, &mut MovingDandelion)>,
+    time: Res<Time>,
+    play_area: Res<PlayArea>,
+    mut rng: ResMut<GameRng>,
+) {
+    let bounds = play_area.grass_bounds(50.0);
 
-            // Change direction randomly
-            if moving.direction_change_timer.just_finished() {
-                let mut rng = rand::thread_rng();
-                let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-                moving.velocity = Vec2::new(angle.cos(), angle.sin()) * moving.speed;
-            }
+    for (mut transform, mut moving) in moving_query.iter_mut() {
+        moving.direction_change_timer.tick(time.delta());
 
-            let delta = moving.velocity * time.delta_secs();
-            let new_pos = transform.translation.truncate() + delta;
+        // Change direction randomly
+        if moving.direction_change_timer.just_finished() {
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            moving.velocity = Vec2::new(angle.cos(), angle.sin()) * moving.speed;
+        }
 
-            // Bounce off boundaries
-            let mut velocity = moving.velocity;
-            if new_pos.x < bounds.min.x || new_pos.x > bounds.max.x {
-                velocity.x = -velocity.x;
-            }
-            if new_pos.y < bounds.min.y || new_pos.y > bounds.max.y {
-                velocity.y = -velocity.y;
-            }
-            moving.velocity = velocity;
+        let delta = moving.velocity * time.delta_secs();
+        let new_pos = transform.translation.truncate() + delta;
 
-            // Update position with boundary clamping
-            let clamped_pos = new_pos.clamp(bounds.min, bounds.max);
-            transform.translation = Vec3::new(clamped_pos.x, clamped_pos.y, transform.translation.z);
+        // Bounce off boundaries
+        let mut velocity = moving.velocity;
+        if new_pos.x < bounds.min.x || new_pos.x > bounds.max.x {
+            velocity.x = -velocity.x;
+        }
+        if new_pos.y < bounds.min.y || new_pos.y > bounds.max.y {
+            velocity.y = -velocity.y;
         }
+        moving.velocity = velocity;
+
+        // Update position with boundary clamping
+        let clamped_pos = new_pos.clamp(bounds.min, bounds.max);
+        transform.translation = Vec3::new(clamped_pos.x, clamped_pos.y, transform.translation.z);
+    }
+}
+
+/// Index every dandelion's current position
+fn rebuild_dandelion_grid(mut grid: ResMut<DandelionGrid>, dandelion_query: Query<(Entity, &Transform), With<Dandelion>>) {
+    grid.clear();
+    for (entity, transform) in dandelion_query.iter() {
+        grid.insert(entity, transform.translation.truncate());
     }
 }
 
@@ -1125,6 +1637,7 @@ fn check_moving_dandelion_collisions(
     mut commands: Commands,
     moving_query: Query<(Entity, &Transform, &Dandelion), With<MovingDandelion>>,
     mut stationary_query: Query<(Entity, &Transform, &mut Dandelion), (Without<MovingDandelion>, With<Dandelion>, Without<UpgradeCooldown>)>,
+    grid: Res<DandelionGrid>,
 ) {
     let mut upgrades_this_frame = 0;
     const MAX_UPGRADES_PER_FRAME: usize = 10; // Limit to prevent performance issues
@@ -1137,7 +1650,15 @@ fn check_moving_dandelion_collisions(
         let moving_pos = moving_transform.translation.truncate();
         let moving_radius = moving_dandelion.size.collision_radius();
 
-        for (stationary_entity, stationary_transform, mut stationary_dandelion) in stationary_query.iter_mut() {
+        let reach = moving_radius + DandelionSize::max_collision_radius();
+        for (entity, _) in grid.query_radius(moving_pos, reach) {
+            let Ok((stationary_entity, stationary_transform, mut stationary_dandelion)) = stationary_query.get_mut(entity) else {
+                continue;
+            };
+            // Only dandelions grow from a huge one rolling over them
+            if stationary_dandelion.species != Species::Dandelion {
+                continue;
+            }
             if upgrades_this_frame >= MAX_UPGRADES_PER_FRAME {
                 break 'outer;
             }
@@ -1191,11 +1712,12 @@ fn spawn_variety_dandelions(
     mut commands: Commands,
     mut variety_timer: ResMut<VarietySpawnTimer>,
     time: Res<Time>,
-    windows: Query<&Window>,
-    asset_server: Res<AssetServer>,
+    play_area: Res<PlayArea>,
+    game_assets: Res<GameAssets>,
     mut game_data: ResMut<GameData>,
     mut area_tracker: ResMut<DandelionAreaTracker>,
     level_data: Option<Res<LevelData>>,
+    mut rng: ResMut<GameRng>,
 ) {
     // Use level-based difficulty threshold instead of fixed threshold
     let difficulty_threshold = if let Some(level_data) = &level_data {
@@ -1234,78 +1756,99 @@ fn spawn_variety_dandelions(
     variety_timer.timer.tick(adjusted_delta);
 
     if variety_timer.timer.just_finished() {
-        if let Ok(window) = windows.single() {
-            let mut rng = rand::thread_rng();
-
-            // Calculate safe spawn area
-            let margin = 30.0;
-            let top_ui_height = window.height() * 0.12;
-            let bottom_ui_height = window.height() * 0.08;
-
-            let min_x = -window.width() / 2.0 + margin;
-            let max_x = window.width() / 2.0 - margin;
-            let min_y = -window.height() / 2.0 + bottom_ui_height + margin;
-            let max_y = window.height() / 2.0 - top_ui_height - margin;
-
-            // Spawn one of each size
-            let sizes = [
-                DandelionSize::Tiny,
-                DandelionSize::Small,
-                DandelionSize::Medium,
-                DandelionSize::Large,
-                DandelionSize::Huge,
-            ];
-
-            // Apply level-based health scaling
-            let health_multiplier = if let Some(level_data) = &level_data {
-                if let Some(current_level) = level_data.levels.get((level_data.current_level - 1) as usize) {
-                    current_level.enemy_scaling.health_multiplier
-                } else {
-                    1.0
-                }
+        // Calculate safe spawn area
+        let bounds = play_area.grass_bounds(30.0);
+
+        // Spawn one of each size
+        let sizes = [
+            DandelionSize::Tiny,
+            DandelionSize::Small,
+            DandelionSize::Medium,
+            DandelionSize::Large,
+            DandelionSize::Huge,
+        ];
+
+        // Apply level-based health scaling
+        let health_multiplier = if let Some(level_data) = &level_data {
+            if let Some(current_level) = level_data.levels.get((level_data.current_level - 1) as usize) {
+                current_level.enemy_scaling.health_multiplier
             } else {
                 1.0
-            };
+            }
+        } else {
+            1.0
+        };
 
-            for size in sizes {
-                let x = rng.gen_range(min_x..max_x);
-                let y = rng.gen_range(min_y..max_y);
-
-                // Calculate scaled health based on size and level
-                let base_health = match size {
-                    DandelionSize::Tiny => 1,
-                    DandelionSize::Small => 2,
-                    DandelionSize::Medium => 3,
-                    DandelionSize::Large => 4,
-                    DandelionSize::Huge => 5,
-                };
-                let health = (base_health as f32 * health_multiplier).ceil() as u32;
-
-                let mut entity_commands = commands.spawn((
-                    Sprite {
-                        image: asset_server.load(size.asset_path()),
-                        color: Color::WHITE,
-                        ..default()
-                    },
-                    Transform::from_translation(Vec3::new(x, y, 10.0)).with_scale(Vec3::splat(size.scale())),
-                    Dandelion { health, size },
-                    EnemyEntity,
-                ));
+        for size in sizes {
+            let x = rng.gen_range(bounds.min.x..bounds.max.x);
+            let y = rng.gen_range(bounds.min.y..bounds.max.y);
+
+            // Calculate scaled health based on size and level
+            let base_health = match size {
+                DandelionSize::Tiny => 1,
+                DandelionSize::Small => 2,
+                DandelionSize::Medium => 3,
+                DandelionSize::Large => 4,
+                DandelionSize::Huge => 5,
+            };
+            let health = (base_health as f32 * health_multiplier).ceil() as u32;
 
-                // Add moving component if huge size
-                if size == DandelionSize::Huge {
-                    entity_commands.insert(MovingDandelion::default());
-                }
+            let mut entity_commands = commands.spawn((
+                Sprite {
+                    image: size.image(&game_assets),
+                    color: Color::WHITE,
+                    ..default()
+                },
+                Transform::from_translation(Vec3::new(x, y, 10.0)).with_scale(Vec3::splat(size.scale())),
+                Dandelion {
+                    health,
+                    size,
+                    species: Species::Dandelion,
+                },
+                Flower::default(),
+                EnemyEntity,
+            ));
 
-                game_data.dandelion_count += 1;
-                area_tracker.total_area += size.visual_area();
+            // Add moving component if huge size
+            if size == DandelionSize::Huge {
+                entity_commands.insert(MovingDandelion::new(&mut *rng));
             }
 
-            debug!(
-                "Spawned variety pack of dandelions (difficulty mode) with {}x health scaling",
-                health_multiplier
-            );
+            game_data.dandelion_count += 1;
+            area_tracker.total_area += size.visual_area();
+        }
+
+        // Levels with thistles add a flowering one to the pack
+        let has_thistles = level_data
+            .as_ref()
+            .and_then(|level_data| level_data.levels.get((level_data.current_level - 1) as usize))
+            .is_some_and(|level| level.enemy_scaling.thistle_chance > 0.0);
+        if has_thistles {
+            let x = rng.gen_range(bounds.min.x..bounds.max.x);
+            let y = rng.gen_range(bounds.min.y..bounds.max.y);
+            let size = DandelionSize::Large;
+            commands.spawn((
+                Sprite {
+                    image: Species::Thistle.image(size, &game_assets),
+                    color: Color::WHITE,
+                    ..default()
+                },
+                Transform::from_translation(Vec3::new(x, y, 10.0)).with_scale(Vec3::splat(size.scale())),
+                Dandelion {
+                    health: calculate_max_health(size, Species::Thistle, level_data.as_deref()),
+                    size,
+                    species: Species::Thistle,
+                },
+                EnemyEntity,
+            ));
+            game_data.dandelion_count += 1;
+            area_tracker.total_area += size.visual_area();
         }
+
+        debug!(
+            "Spawned variety pack of dandelions (difficulty mode) with {}x health scaling",
+            health_multiplier
+        );
     }
 }
 
@@ -1328,19 +1871,23 @@ pub fn spawn_dandelion_ring(commands: &mut Commands, assets: &GameAssets, positi
                 ..default()
             },
             Transform::from_translation(Vec3::new(spawn_pos.x, spawn_pos.y, 10.0)).with_scale(Vec3::splat(size.scale())),
-            Dandelion { health: 1, size },
+            Dandelion {
+                health: 1,
+                size,
+                species: Species::Dandelion,
+            },
             EnemyEntity,
         ));
     }
 }
 
-/// Calculate the maximum health for a dandelion based on its size and current level scaling
-fn calculate_max_health(size: DandelionSize, level_data: Option<&LevelData>) -> u32 {
+/// Calculate the maximum health for a weed based on its size, species and current level scaling
+fn calculate_max_health(size: DandelionSize, species: Species, level_data: Option<&LevelData>) -> u32 {
     let base_health = size.base_health();
 
     if let Some(level_data) = level_data {
         if let Some(current_level) = level_data.levels.get((level_data.current_level - 1) as usize) {
-            (base_health as f32 * current_level.enemy_scaling.health_multiplier).ceil() as u32
+            (base_health as f32 * current_level.enemy_scaling.health_multiplier_for(species)).ceil() as u32
         } else {
             base_health
         }
@@ -1422,14 +1969,14 @@ fn manage_health_bars(
     }
 
     for (dandelion_entity, dandelion_transform, dandelion) in dandelion_query.iter() {
-        let max_health = calculate_max_health(dandelion.size, level_data.as_deref());
+        let max_health = calculate_max_health(dandelion.size, dandelion.species, level_data.as_deref());
         let health_percentage = dandelion.health as f32 / max_health as f32;
 
         // Check if dandelion is damaged (less than 100% health)
         if health_percentage < 1.0 {
             // If no health bar exists, create one
             if !existing_health_bars.contains_key(&dandelion_entity) {
-                println!("Creating health bar for damaged dandelion: {:.1}% health", health_percentage * 100.0);
+                debug!("Creating health bar for damaged dandelion: {:.1}% health", health_percentage * 100.0);
                 spawn_health_bar(&mut commands, dandelion_entity, dandelion_transform, dandelion, max_health);
             }
         } else {
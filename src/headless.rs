@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+use crate::combos::CombosPlugin;
+use crate::enemies::EnemiesPlugin;
+use crate::input::PlayerInput;
+use crate::levels::LevelsPlugin;
+use crate::pause_menu::PauseState;
+use crate::play_area::PlayArea;
+use crate::playing::{GameData, update_combo_timer};
+use crate::powerups::PowerupsPlugin;
+use crate::rng::RngPlugin;
+use crate::wind::WindPlugin;
+use crate::{GameAssets, GameState};
+
+/// Fixed time step of a headless frame
+pub const HEADLESS_FRAME_TIME: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+/// Plugin that runs gameplay without a window, renderer or loaded assets, for use with `MinimalPlugins`.
+/// The app starts in a level; gameplay is driven by writing to `PlayerInput`.
+pub struct HeadlessGameplayPlugin {
+    /// Seed for all gameplay randomness, `None` to use the level's own seed
+    pub seed: Option<u64>,
+}
+
+impl Plugin for HeadlessGameplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(StatesPlugin)
+            .insert_state(GameState::Playing)
+            .insert_state(PauseState::Playing)
+            .add_plugins((
+                EnemiesPlugin,
+                PowerupsPlugin,
+                CombosPlugin,
+                LevelsPlugin,
+                WindPlugin,
+                RngPlugin { seed: self.seed },
+            ))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(HEADLESS_FRAME_TIME))
+            .insert_resource(GameData::new())
+            .init_resource::<PlayArea>()
+            .init_resource::<GameAssets>()
+            .init_resource::<PlayerInput>()
+            // Normally run by the playing UI plugin, but it drives the score
+            .add_systems(
+                Update,
+                update_combo_timer.run_if(in_state(GameState::Playing)).run_if(in_state(PauseState::Playing)),
+            );
+    }
+}
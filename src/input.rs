@@ -0,0 +1,194 @@
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy_enhanced_input::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::actions::{Attack, MoveReticle, PlayingInput, ReticleAttack, ToggleMode, UsePowerup};
+use crate::play_area::PlayArea;
+use crate::playing::AttackModeButton;
+use crate::powerups::{PowerupSlot, PowerupType};
+
+/// Gamepad reticle speed at full stick tilt, in world units per second
+const RETICLE_SPEED: f32 = 600.0;
+
+/// Plugin that turns bound actions and raw touches into per-frame gameplay actions
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerInput>()
+            .init_resource::<GamepadReticle>()
+            .add_systems(PreUpdate, move_gamepad_reticle.after(EnhancedInputSystem))
+            .add_systems(
+                PreUpdate,
+                gather_player_input
+                    .after(InputSystem)
+                    .after(move_gamepad_reticle)
+                    .after(UiSystem::Focus)
+                    .run_if(not(crate::replay::is_replaying)),
+            );
+    }
+}
+
+/// Gameplay actions for the current frame, either from the player or a replay
+#[derive(Resource, Default, Clone, Debug)]
+pub struct PlayerInput {
+    /// Every click or touch that is held, or was let go, this frame
+    pub pointers: Vec<Pointer>,
+    /// Switch between click and slash attack modes
+    pub toggle_slash_mode: bool,
+    /// World position to trigger a field powerup at, without attacking
+    pub use_powerup: Option<Vec2>,
+    /// Inventory slot tapped this frame, arming its powerup for the next tap on the lawn
+    pub select_powerup: Option<PowerupType>,
+}
+
+impl PlayerInput {
+    /// World positions of the clicks and touches that started this frame
+    pub fn presses(&self) -> impl Iterator<Item = Vec2> + '_ {
+        self.pointers
+            .iter()
+            .filter(|pointer| pointer.just_pressed)
+            .filter_map(|pointer| pointer.position)
+    }
+}
+
+/// Identifies the mouse or a finger from press to release
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PointerId {
+    /// The cursor, pressed with the Attack binding
+    Mouse,
+    /// The gamepad reticle, pressed with a trigger
+    Gamepad,
+    Touch(u64),
+}
+
+/// One click or touch on this frame
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pointer {
+    pub id: PointerId,
+    /// World position, `None` if it is off the lawn's camera
+    pub position: Option<Vec2>,
+    /// Pressed this frame
+    pub just_pressed: bool,
+    /// Let go this frame
+    pub just_released: bool,
+}
+
+/// World position the gamepad clicks and slashes at, moved with the left stick
+#[derive(Resource, Default)]
+pub struct GamepadReticle {
+    pub position: Vec2,
+    /// Shown once the gamepad is used, hidden again when the mouse or a touch takes over
+    pub visible: bool,
+}
+
+/// Move the reticle with the stick, keeping it on the grass
+fn move_gamepad_reticle(playing_actions: Query<&Actions<PlayingInput>>, time: Res<Time>, play_area: Res<PlayArea>, mut reticle: ResMut<GamepadReticle>) {
+    let Some(tilt) = playing_actions.single().ok().and_then(|actions| actions.value::<MoveReticle>().ok()) else {
+        return;
+    };
+
+    let tilt = tilt.as_axis2d();
+    if tilt != Vec2::ZERO {
+        reticle.position += tilt * RETICLE_SPEED * time.delta_secs();
+        reticle.visible = true;
+    }
+
+    let bounds = play_area.grass_bounds(0.0);
+    let clamped = reticle.position.clamp(bounds.min, bounds.max);
+    // Only write on change, so the reticle isn't marked changed every frame
+    if clamped != reticle.position {
+        reticle.position = clamped;
+    }
+}
+
+/// Collect this frame's actions from the bound inputs, touches and attack mode button
+fn gather_player_input(
+    mut player_input: ResMut<PlayerInput>,
+    mut reticle: ResMut<GamepadReticle>,
+    playing_actions: Query<&Actions<PlayingInput>>,
+    touches: Res<Touches>,
+    attack_mode_buttons: Query<&Interaction, (Changed<Interaction>, With<AttackModeButton>)>,
+    powerup_slots: Query<(&Interaction, &PowerupSlot), Changed<Interaction>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    player_input.pointers.clear();
+    let actions = playing_actions.single().ok();
+
+    let attack = action_events::<Attack>(actions);
+    if attack.intersects(ActionEvents::FIRED | ActionEvents::COMPLETED) {
+        player_input.pointers.push(Pointer {
+            id: PointerId::Mouse,
+            position: world_cursor_position(&windows, &camera_query),
+            just_pressed: attack.contains(ActionEvents::STARTED),
+            just_released: attack.contains(ActionEvents::COMPLETED),
+        });
+    }
+
+    let to_world = |position| {
+        let (camera, camera_transform) = camera_query.single().ok()?;
+        camera.viewport_to_world_2d(camera_transform, position).ok()
+    };
+    player_input.pointers.extend(touch_pointers(&touches, to_world));
+
+    if !player_input.pointers.is_empty() && reticle.visible {
+        reticle.visible = false;
+    }
+
+    let reticle_attack = action_events::<ReticleAttack>(actions);
+    if reticle_attack.intersects(ActionEvents::FIRED | ActionEvents::COMPLETED) {
+        reticle.visible = true;
+        player_input.pointers.push(Pointer {
+            id: PointerId::Gamepad,
+            position: Some(reticle.position),
+            just_pressed: reticle_attack.contains(ActionEvents::STARTED),
+            just_released: reticle_attack.contains(ActionEvents::COMPLETED),
+        });
+    }
+
+    let button_pressed = attack_mode_buttons.iter().any(|interaction| *interaction == Interaction::Pressed);
+    player_input.toggle_slash_mode = action_events::<ToggleMode>(actions).contains(ActionEvents::STARTED) || button_pressed;
+
+    player_input.use_powerup = action_events::<UsePowerup>(actions)
+        .contains(ActionEvents::STARTED)
+        .then(|| world_cursor_position(&windows, &camera_query))
+        .flatten();
+
+    player_input.select_powerup = powerup_slots
+        .iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, slot)| slot.0);
+}
+
+/// This frame's events for action `A`, empty while the playing context is inactive
+fn action_events<A: InputAction>(actions: Option<&Actions<PlayingInput>>) -> ActionEvents {
+    actions.and_then(|actions| actions.events::<A>().ok()).unwrap_or(ActionEvents::empty())
+}
+
+/// Convert the cursor position to world coordinates
+pub fn world_cursor_position(windows: &Query<&Window>, camera_query: &Query<(&Camera, &GlobalTransform)>) -> Option<Vec2> {
+    let window = windows.single().ok()?;
+    let (camera, camera_transform) = camera_query.single().ok()?;
+    let cursor_pos = window.cursor_position()?;
+    camera.viewport_to_world_2d(camera_transform, cursor_pos).ok()
+}
+
+/// Every touch that is held or was let go this frame, ordered by id, mapped to the world with `to_world`
+pub fn touch_pointers(touches: &Touches, to_world: impl Fn(Vec2) -> Option<Vec2>) -> Vec<Pointer> {
+    let mut pointers: Vec<Pointer> = touches
+        .iter()
+        .chain(touches.iter_just_released())
+        .map(|touch| Pointer {
+            id: PointerId::Touch(touch.id()),
+            position: to_world(touch.position()),
+            just_pressed: touches.just_pressed(touch.id()),
+            just_released: touches.just_released(touch.id()),
+        })
+        .collect();
+    pointers.sort_by_key(|pointer| pointer.id);
+    pointers.dedup_by_key(|pointer| pointer.id);
+    pointers
+}
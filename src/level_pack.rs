@@ -0,0 +1,230 @@
+use bevy::asset::{AssetLoadFailedEvent, AssetLoader, LoadContext, io::Reader};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::fmt;
+
+use crate::levels::{Level, LevelData, LevelValidationError, validate_levels};
+
+/// Level pack loaded at startup; edits hot reload when the `file_watcher` feature is enabled
+const LEVEL_PACK_PATH: &str = "levels/default.levels.ron";
+
+/// The same pack as a file path, for tools that run without the asset server
+pub const LEVEL_PACK_FILE: &str = "assets/levels/default.levels.ron";
+
+/// Plugin that loads level definitions from a data file through the asset system
+pub struct LevelPackPlugin;
+
+impl Plugin for LevelPackPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<LevelPack>()
+            .init_asset_loader::<LevelPackLoader>()
+            .add_systems(Startup, load_level_pack)
+            .add_systems(Update, (apply_level_pack, report_level_pack_failures));
+    }
+}
+
+/// A set of level definitions loaded from a `.levels.ron` file
+#[derive(Asset, TypePath, Deserialize)]
+pub struct LevelPack {
+    pub levels: Vec<Level>,
+}
+
+/// Handle keeping the active level pack loaded (and watched for changes)
+#[derive(Resource)]
+struct LevelPackHandle {
+    _handle: Handle<LevelPack>,
+}
+
+/// Errors produced while parsing a level pack
+#[derive(Debug)]
+pub enum LevelPackError {
+    Io(std::io::Error),
+    /// The file is not valid RON
+    Syntax(ron::error::SpannedError),
+    /// A level entry has a missing or malformed field
+    InvalidField {
+        level_id: Option<u32>,
+        index: usize,
+        field: String,
+        message: String,
+    },
+    /// The pack structure itself is wrong (e.g. `levels` missing)
+    InvalidPack(String),
+    /// The pack parsed but breaks level invariants
+    Validation(Vec<LevelValidationError>),
+}
+
+impl fmt::Display for LevelPackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LevelPackError::Io(err) => write!(f, "could not read level pack: {}", err),
+            LevelPackError::Syntax(err) => write!(f, "level pack is not valid RON: {}", err),
+            LevelPackError::InvalidField {
+                level_id,
+                index,
+                field,
+                message,
+            } => match level_id {
+                Some(level_id) => write!(f, "level {} (entry {}), field `{}`: {}", level_id, index, field, message),
+                None => write!(f, "level entry {}, field `{}`: {}", index, field, message),
+            },
+            LevelPackError::InvalidPack(message) => write!(f, "invalid level pack: {}", message),
+            LevelPackError::Validation(errors) => {
+                let errors = errors.iter().map(|err| err.to_string()).collect::<Vec<_>>();
+                write!(f, "{}", errors.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for LevelPackError {}
+
+impl From<std::io::Error> for LevelPackError {
+    fn from(err: std::io::Error) -> Self {
+        LevelPackError::Io(err)
+    }
+}
+
+/// Asset loader for `.levels.ron` files
+#[derive(Default)]
+struct LevelPackLoader;
+
+impl AssetLoader for LevelPackLoader {
+    type Asset = LevelPack;
+    type Settings = ();
+    type Error = LevelPackError;
+
+    async fn load(&self, reader: &mut dyn Reader, _settings: &(), _load_context: &mut LoadContext<'_>) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        parse_level_pack(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["levels.ron"]
+    }
+}
+
+/// Parse and validate a level pack, reporting the offending level id and field on failure
+pub fn parse_level_pack(bytes: &[u8]) -> Result<LevelPack, LevelPackError> {
+    let mut deserializer = ron::Deserializer::from_bytes(bytes).map_err(LevelPackError::Syntax)?;
+
+    let pack = match serde_path_to_error::deserialize::<_, LevelPack>(&mut deserializer) {
+        Ok(pack) => pack,
+        Err(err) => {
+            let path = err.path().clone();
+            let message = err.into_inner().to_string();
+            return Err(describe_field_error(bytes, &path, message));
+        }
+    };
+
+    if pack.levels.is_empty() {
+        return Err(LevelPackError::InvalidPack("pack contains no levels".to_string()));
+    }
+
+    let errors = validate_levels(&pack.levels);
+    if !errors.is_empty() {
+        return Err(LevelPackError::Validation(errors));
+    }
+
+    Ok(pack)
+}
+
+/// Read and parse a level pack straight from disk
+pub fn read_level_pack(path: &str) -> Result<LevelPack, LevelPackError> {
+    let bytes = std::fs::read(path)?;
+    parse_level_pack(&bytes)
+}
+
+/// Headless `validate-levels [PATH...]` command: check level packs without starting the game
+pub fn run_validate_levels(paths: &[String]) -> AppExit {
+    let paths = if paths.is_empty() {
+        vec![LEVEL_PACK_FILE.to_string()]
+    } else {
+        paths.to_vec()
+    };
+
+    let mut failed = false;
+    for path in &paths {
+        match read_level_pack(path) {
+            Ok(pack) => println!("{}: ok ({} levels)", path, pack.levels.len()),
+            Err(LevelPackError::Validation(errors)) => {
+                failed = true;
+                eprintln!("{}: {} problem(s)", path, errors.len());
+                for err in errors {
+                    eprintln!("  {}", err);
+                }
+            }
+            Err(err) => {
+                failed = true;
+                eprintln!("{}: {}", path, err);
+            }
+        }
+    }
+
+    if failed { AppExit::error() } else { AppExit::Success }
+}
+
+/// Turn a serde path like `levels[3].time_limits.two_star` into a level-scoped error
+fn describe_field_error(bytes: &[u8], path: &serde_path_to_error::Path, message: String) -> LevelPackError {
+    let mut segments = path.iter();
+
+    if !matches!(segments.next(), Some(serde_path_to_error::Segment::Map { key }) if key == "levels") {
+        return LevelPackError::InvalidPack(format!("{} (at `{}`)", message, path));
+    }
+
+    let Some(serde_path_to_error::Segment::Seq { index }) = segments.next() else {
+        return LevelPackError::InvalidPack(format!("{} (at `{}`)", message, path));
+    };
+
+    let field = segments.map(|segment| segment.to_string()).collect::<Vec<_>>().join(".");
+
+    LevelPackError::InvalidField {
+        level_id: find_level_id(bytes, *index),
+        index: *index,
+        field: if field.is_empty() { "<level>".to_string() } else { field },
+        message,
+    }
+}
+
+/// Best-effort lookup of a level's `id` so errors can name it even when the entry is malformed
+fn find_level_id(bytes: &[u8], index: usize) -> Option<u32> {
+    #[derive(Deserialize)]
+    struct LevelIds {
+        levels: Vec<ron::Value>,
+    }
+
+    let pack: LevelIds = ron::de::from_bytes(bytes).ok()?;
+    let ron::Value::Map(mut level) = pack.levels.into_iter().nth(index)? else {
+        return None;
+    };
+    level.remove(&ron::Value::String("id".to_string()))?.into_rust::<u32>().ok()
+}
+
+/// Start loading the level pack
+fn load_level_pack(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(LevelPackHandle {
+        _handle: asset_server.load(LEVEL_PACK_PATH),
+    });
+}
+
+/// Swap in level definitions whenever the pack finishes loading or is hot reloaded
+fn apply_level_pack(mut events: EventReader<AssetEvent<LevelPack>>, packs: Res<Assets<LevelPack>>, mut level_data: ResMut<LevelData>) {
+    for event in events.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = event else {
+            continue;
+        };
+
+        if let Some(pack) = packs.get(*id) {
+            level_data.replace_levels(pack.levels.clone());
+            info!("Loaded {} levels from {}", pack.levels.len(), LEVEL_PACK_PATH);
+        }
+    }
+}
+
+/// Keep the built-in levels when the pack can't be loaded
+fn report_level_pack_failures(mut events: EventReader<AssetLoadFailedEvent<LevelPack>>) {
+    for event in events.read() {
+        warn!("Failed to load level pack {}, using built-in levels: {}", event.path, event.error);
+    }
+}
@@ -1,13 +1,25 @@
 use bevy::prelude::*;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::time::Duration;
 
+use crate::enemies::Species;
+use crate::powerups::{PowerupInventory, PowerupUpgrades, Upgrade};
+
 /// Level configuration and progression system
 #[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct LevelData {
     pub current_level: u32,
     pub levels: Vec<Level>,
     pub level_progress: Vec<LevelProgress>,
+    /// Powerup charges earned from stars
+    #[serde(default)]
+    pub powerup_inventory: PowerupInventory,
+    /// Permanent powerup upgrades bought with stars
+    #[serde(default)]
+    pub upgrades: PowerupUpgrades,
+    /// Saved current level that isn't in the level list yet, selected once a level pack provides it
+    #[serde(skip)]
+    pub pending_current_level: Option<u32>,
 }
 
 impl Default for LevelData {
@@ -18,12 +30,15 @@ impl Default for LevelData {
             current_level: 1,
             levels,
             level_progress: vec![LevelProgress::default(); level_count],
+            powerup_inventory: PowerupInventory::default(),
+            upgrades: PowerupUpgrades::default(),
+            pending_current_level: None,
         }
     }
 }
 
 impl LevelData {
-    /// Create the default set of levels with increasing difficulty
+    /// Create the built-in set of levels, used when no level pack is available
     fn create_default_levels() -> Vec<Level> {
         vec![
             Level {
@@ -39,11 +54,18 @@ impl LevelData {
                     health_multiplier: 1.0,
                     spawn_rate_multiplier: 1.0,
                     difficulty_threshold: 200,
+                    clover_chance: 0.0,
+                    thistle_chance: 0.0,
+                    thistle_health_multiplier: 1.0,
+                    wind_strength: 0.0,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 0,
                     required_stars: 0,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: None,
             },
             Level {
                 id: 2,
@@ -58,11 +80,18 @@ impl LevelData {
                     health_multiplier: 1.2,
                     spawn_rate_multiplier: 1.1,
                     difficulty_threshold: 300,
+                    clover_chance: 0.0,
+                    thistle_chance: 0.0,
+                    thistle_health_multiplier: 1.0,
+                    wind_strength: 0.0,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 1,
                     required_stars: 1,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: None,
             },
             Level {
                 id: 3,
@@ -77,11 +106,18 @@ impl LevelData {
                     health_multiplier: 1.5,
                     spawn_rate_multiplier: 1.2,
                     difficulty_threshold: 400,
+                    clover_chance: 0.1,
+                    thistle_chance: 0.0,
+                    thistle_health_multiplier: 1.0,
+                    wind_strength: 0.0,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 2,
                     required_stars: 2,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: None,
             },
             Level {
                 id: 4,
@@ -96,11 +132,18 @@ impl LevelData {
                     health_multiplier: 1.8,
                     spawn_rate_multiplier: 1.3,
                     difficulty_threshold: 500,
+                    clover_chance: 0.1,
+                    thistle_chance: 0.0,
+                    thistle_health_multiplier: 1.0,
+                    wind_strength: 0.2,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 3,
                     required_stars: 4,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: None,
             },
             Level {
                 id: 5,
@@ -115,11 +158,18 @@ impl LevelData {
                     health_multiplier: 2.2,
                     spawn_rate_multiplier: 1.4,
                     difficulty_threshold: 600,
+                    clover_chance: 0.15,
+                    thistle_chance: 0.0,
+                    thistle_health_multiplier: 1.0,
+                    wind_strength: 0.2,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 4,
                     required_stars: 6,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: None,
             },
             Level {
                 id: 6,
@@ -134,11 +184,18 @@ impl LevelData {
                     health_multiplier: 2.5,
                     spawn_rate_multiplier: 1.5,
                     difficulty_threshold: 700,
+                    clover_chance: 0.15,
+                    thistle_chance: 0.05,
+                    thistle_health_multiplier: 1.5,
+                    wind_strength: 0.3,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 5,
                     required_stars: 8,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: None,
             },
             Level {
                 id: 7,
@@ -153,11 +210,18 @@ impl LevelData {
                     health_multiplier: 3.0,
                     spawn_rate_multiplier: 1.6,
                     difficulty_threshold: 800,
+                    clover_chance: 0.2,
+                    thistle_chance: 0.05,
+                    thistle_health_multiplier: 1.5,
+                    wind_strength: 0.3,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 6,
                     required_stars: 10,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: None,
             },
             Level {
                 id: 8,
@@ -172,11 +236,18 @@ impl LevelData {
                     health_multiplier: 3.5,
                     spawn_rate_multiplier: 1.8,
                     difficulty_threshold: 900,
+                    clover_chance: 0.2,
+                    thistle_chance: 0.1,
+                    thistle_health_multiplier: 2.0,
+                    wind_strength: 0.4,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 7,
                     required_stars: 12,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: None,
             },
             Level {
                 id: 9,
@@ -191,11 +262,18 @@ impl LevelData {
                     health_multiplier: 4.0,
                     spawn_rate_multiplier: 2.0,
                     difficulty_threshold: 1000,
+                    clover_chance: 0.25,
+                    thistle_chance: 0.1,
+                    thistle_health_multiplier: 2.0,
+                    wind_strength: 0.4,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 8,
                     required_stars: 15,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: Some(Duration::from_secs(8)),
             },
             Level {
                 id: 10,
@@ -210,11 +288,18 @@ impl LevelData {
                     health_multiplier: 5.0,
                     spawn_rate_multiplier: 2.5,
                     difficulty_threshold: 1200,
+                    clover_chance: 0.25,
+                    thistle_chance: 0.15,
+                    thistle_health_multiplier: 2.5,
+                    wind_strength: 0.5,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 9,
                     required_stars: 18,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: Some(Duration::from_secs(7)),
             },
             Level {
                 id: 11,
@@ -229,11 +314,18 @@ impl LevelData {
                     health_multiplier: 6.0,
                     spawn_rate_multiplier: 3.0,
                     difficulty_threshold: 1500,
+                    clover_chance: 0.3,
+                    thistle_chance: 0.15,
+                    thistle_health_multiplier: 3.0,
+                    wind_strength: 0.6,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 10,
                     required_stars: 20,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: Some(Duration::from_secs(6)),
             },
             Level {
                 id: 12,
@@ -248,11 +340,18 @@ impl LevelData {
                     health_multiplier: 7.0,
                     spawn_rate_multiplier: 3.5,
                     difficulty_threshold: 2000,
+                    clover_chance: 0.3,
+                    thistle_chance: 0.2,
+                    thistle_health_multiplier: 3.5,
+                    wind_strength: 0.7,
                 },
                 unlock_requirements: UnlockRequirements {
                     required_level: 11,
                     required_stars: 25,
                 },
+                time_limit: None,
+                seed: None,
+                root_regrow_time: Some(Duration::from_secs(5)),
             },
         ]
     }
@@ -294,8 +393,10 @@ impl LevelData {
             }
 
             // Check if previous level completed with required stars
-            let total_stars: u32 = self.level_progress[0..level.unlock_requirements.required_level as usize]
+            let total_stars: u32 = self
+                .level_progress
                 .iter()
+                .take(level.unlock_requirements.required_level as usize)
                 .map(|p| p.best_stars)
                 .sum();
 
@@ -305,7 +406,7 @@ impl LevelData {
         }
     }
 
-    /// Update level progress after completion
+    /// Update level progress after completion, granting a powerup charge for every star beyond the level's previous best
     pub fn complete_level(&mut self, level_id: u32, completion_time: Duration, final_score: u32) {
         // Get level data first to avoid borrow checker issues
         let time_limits = if let Some(level) = self.get_level(level_id) {
@@ -316,14 +417,18 @@ impl LevelData {
 
         if let Some(progress) = self.get_level_progress_mut(level_id) {
             let stars = calculate_stars(&time_limits, completion_time);
+            let new_stars = stars.saturating_sub(progress.best_stars);
 
             // Update progress if this is a better result
             if final_score > progress.best_score || (final_score == progress.best_score && completion_time < progress.best_time) {
                 progress.best_score = final_score;
                 progress.best_time = completion_time;
-                progress.best_stars = stars.max(progress.best_stars);
                 progress.completed = true;
             }
+            // Stars are kept separately, since a lower score can still come in faster
+            progress.best_stars = stars.max(progress.best_stars);
+
+            self.powerup_inventory.grant_stars(new_stars);
 
             info!(
                 "Level {} completed! Score: {}, Time: {:?}, Stars: {}",
@@ -342,7 +447,36 @@ impl LevelData {
     pub fn set_current_level(&mut self, level_id: u32) {
         if self.is_level_unlocked(level_id) {
             self.current_level = level_id;
+            self.pending_current_level = None;
+        }
+    }
+
+    /// Replace the level definitions (e.g. from a level pack) while keeping existing progress,
+    /// including progress on levels beyond the new list
+    pub fn replace_levels(&mut self, levels: Vec<Level>) {
+        self.levels = levels;
+        let progress_len = self.level_progress.len().max(self.levels.len());
+        self.level_progress.resize(progress_len, LevelProgress::default());
+
+        if let Some(level_id) = self.pending_current_level
+            && self.get_level(level_id).is_some()
+        {
+            self.set_current_level(level_id);
         }
+        if self.get_level(self.current_level).is_none() {
+            self.current_level = 1;
+        }
+    }
+
+    /// Stars earned but not yet spent on upgrades
+    pub fn unspent_stars(&self) -> u32 {
+        self.get_total_stars().saturating_sub(self.upgrades.stars_spent())
+    }
+
+    /// Buy the next level of an upgrade with unspent stars, returning false if it can't be afforded or is maxed out
+    pub fn buy_upgrade(&mut self, upgrade: Upgrade) -> bool {
+        let available = self.unspent_stars();
+        self.upgrades.buy(upgrade, available)
     }
 
     /// Reset all level progress to initial state
@@ -353,6 +487,8 @@ impl LevelData {
         // Reset all level progress to default state
         let level_count = self.levels.len();
         self.level_progress = vec![LevelProgress::default(); level_count];
+        self.powerup_inventory = PowerupInventory::default();
+        self.upgrades = PowerupUpgrades::default();
 
         info!("All level progress has been reset to initial state");
     }
@@ -367,22 +503,90 @@ pub struct Level {
     pub time_limits: TimeLimits,
     pub enemy_scaling: EnemyScaling,
     pub unlock_requirements: UnlockRequirements,
+    /// Hard limit after which the level is failed; `None` lets the level run indefinitely
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "optional_duration_secs")]
+    pub time_limit: Option<Duration>,
+    /// Fixed random seed for every attempt (e.g. daily challenges); `None` picks a new seed per attempt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Killed dandelions leave a root that regrows after this long unless it is dug up or burned; `None` leaves no roots
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "optional_duration_secs")]
+    pub root_regrow_time: Option<Duration>,
 }
 
 /// Time limits for star ratings
 #[derive(Clone, Serialize, Deserialize)]
 pub struct TimeLimits {
+    #[serde(with = "duration_secs")]
     pub three_star: Duration,
+    #[serde(with = "duration_secs")]
     pub two_star: Duration,
+    #[serde(with = "duration_secs")]
     pub one_star: Duration,
 }
 
+/// Serialize durations as plain seconds so level files stay easy to edit by hand
+mod duration_secs {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f32(duration.as_secs_f32())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let secs = f32::deserialize(deserializer)?;
+        Duration::try_from_secs_f32(secs).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Like `duration_secs`, but a missing field means no duration
+mod optional_duration_secs {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+        match duration {
+            Some(duration) => duration_secs::serialize(duration, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Duration>, D::Error> {
+        duration_secs::deserialize(deserializer).map(Some)
+    }
+}
+
 /// Enemy difficulty scaling for the level
 #[derive(Clone, Serialize, Deserialize)]
 pub struct EnemyScaling {
     pub health_multiplier: f32,
     pub spawn_rate_multiplier: f32,
     pub difficulty_threshold: u32, // Score when variety spawning begins
+    /// Chance that a regular spawn is a clover patch instead of a dandelion
+    #[serde(default)]
+    pub clover_chance: f32,
+    /// Chance that a regular spawn is a young thistle instead of a dandelion
+    #[serde(default)]
+    pub thistle_chance: f32,
+    /// Replaces `health_multiplier` for thistles
+    #[serde(default = "default_thistle_health_multiplier")]
+    pub thistle_health_multiplier: f32,
+    /// How hard the wind blows seeds across the lawn, from 0 for still air to 1 for a full gale
+    #[serde(default)]
+    pub wind_strength: f32,
+}
+
+fn default_thistle_health_multiplier() -> f32 {
+    1.0
+}
+
+impl EnemyScaling {
+    /// Health multiplier for weeds of `species`
+    pub fn health_multiplier_for(&self, species: Species) -> f32 {
+        match species {
+            Species::Thistle => self.thistle_health_multiplier,
+            Species::Dandelion | Species::Clover => self.health_multiplier,
+        }
+    }
 }
 
 /// Requirements to unlock a level
@@ -425,25 +629,145 @@ pub fn calculate_stars(time_limits: &TimeLimits, completion_time: Duration) -> u
     }
 }
 
+/// A broken invariant in a set of level definitions
+#[derive(Debug, Clone)]
+pub struct LevelValidationError {
+    pub level_id: u32,
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl std::fmt::Display for LevelValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "level {}, field `{}`: {}", self.level_id, self.field, self.message)
+    }
+}
+
+/// Check the invariants `LevelData` relies on, returning every violation found
+pub fn validate_levels(levels: &[Level]) -> Vec<LevelValidationError> {
+    let mut errors = Vec::new();
+    let mut error = |level: &Level, field: &'static str, message: String| {
+        errors.push(LevelValidationError {
+            level_id: level.id,
+            field,
+            message,
+        });
+    };
+
+    for (index, level) in levels.iter().enumerate() {
+        // Progress is stored by position, so ids must be 1, 2, 3, ...
+        let expected_id = index as u32 + 1;
+        if level.id != expected_id {
+            error(level, "id", format!("expected id {} (ids must be contiguous from 1)", expected_id));
+        }
+
+        let limits = &level.time_limits;
+        if limits.three_star > limits.two_star {
+            error(
+                level,
+                "time_limits.three_star",
+                format!("{:?} is longer than two_star ({:?})", limits.three_star, limits.two_star),
+            );
+        }
+        if limits.two_star > limits.one_star {
+            error(
+                level,
+                "time_limits.two_star",
+                format!("{:?} is longer than one_star ({:?})", limits.two_star, limits.one_star),
+            );
+        }
+
+        if let Some(time_limit) = level.time_limit.filter(|limit| *limit < limits.one_star) {
+            error(
+                level,
+                "time_limit",
+                format!("{:?} is shorter than one_star ({:?})", time_limit, limits.one_star),
+            );
+        }
+        if level.root_regrow_time.is_some_and(|regrow_time| regrow_time.is_zero()) {
+            error(level, "root_regrow_time", "roots must take some time to regrow".to_string());
+        }
+
+        let unlock = &level.unlock_requirements;
+        if unlock.required_level >= level.id {
+            error(
+                level,
+                "unlock_requirements.required_level",
+                format!("{} must refer to an earlier level", unlock.required_level),
+            );
+        }
+        let max_stars = unlock.required_level.min(index as u32) * 3;
+        if unlock.required_stars > max_stars {
+            error(
+                level,
+                "unlock_requirements.required_stars",
+                format!("{} stars can't be earned from earlier levels (at most {})", unlock.required_stars, max_stars),
+            );
+        }
+
+        let scaling = &level.enemy_scaling;
+        for (field, value) in [
+            ("enemy_scaling.health_multiplier", scaling.health_multiplier),
+            ("enemy_scaling.spawn_rate_multiplier", scaling.spawn_rate_multiplier),
+            ("enemy_scaling.thistle_health_multiplier", scaling.thistle_health_multiplier),
+        ] {
+            if !(value.is_finite() && value > 0.0) {
+                error(level, field, format!("{} must be a positive number", value));
+            }
+        }
+        for (field, value) in [
+            ("enemy_scaling.clover_chance", scaling.clover_chance),
+            ("enemy_scaling.thistle_chance", scaling.thistle_chance),
+            ("enemy_scaling.wind_strength", scaling.wind_strength),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                error(level, field, format!("{} must be between 0 and 1", value));
+            }
+        }
+    }
+
+    errors
+}
+
 /// Resource to track current level session
 #[derive(Resource, Default)]
 pub struct LevelSession {
     pub elapsed_time: Duration,
     pub target_reached: bool,
+    pub failed: bool,
+    /// Roots left behind by killed dandelions
+    pub roots_left: u32,
+    /// Roots dug up or burned before they could regrow
+    pub roots_cleared: u32,
+    /// Roots that grew back into dandelions
+    pub roots_regrown: u32,
 }
 
 impl LevelSession {
     pub fn start(&mut self) {
         self.elapsed_time = Duration::ZERO;
         self.target_reached = false;
+        self.failed = false;
+        self.roots_left = 0;
+        self.roots_cleared = 0;
+        self.roots_regrown = 0;
     }
 
     pub fn update(&mut self, delta: Duration) {
-        if !self.target_reached {
+        if self.is_in_progress() {
             self.elapsed_time += delta;
         }
     }
 
+    /// True until the level is either completed or failed
+    pub fn is_in_progress(&self) -> bool {
+        !self.target_reached && !self.failed
+    }
+
+    pub fn fail(&mut self) {
+        self.failed = true;
+    }
+
     pub fn get_elapsed_time(&self) -> Duration {
         self.elapsed_time
     }
@@ -474,7 +798,6 @@ pub struct LevelFailedEvent {
 }
 
 #[derive(Clone, Debug)]
-#[allow(dead_code)]
 pub enum FailureReason {
     TimeOut,
     PlayerQuit,
@@ -495,16 +818,25 @@ impl Plugin for LevelsPlugin {
                 (check_level_completion, handle_level_events)
                     .run_if(in_state(crate::GameState::Playing))
                     .run_if(in_state(crate::pause_menu::PauseState::Playing)),
-            );
+            )
+            .add_systems(PreUpdate, handle_level_starts.run_if(in_state(crate::GameState::Playing)))
+            // Failures can be sent from the pause menu right before leaving the playing state
+            .add_systems(Update, handle_level_failures);
     }
 }
 
+/// Run condition that stops spawning once the current level has been failed
+pub fn level_not_failed(level_session: Option<Res<LevelSession>>) -> bool {
+    level_session.is_none_or(|session| !session.failed)
+}
+
 /// Check if current level is completed based on score
 fn check_level_completion(
     level_data: Res<LevelData>,
     game_data: Res<crate::playing::GameData>,
     mut level_session: ResMut<LevelSession>,
     mut level_complete_events: EventWriter<LevelCompleteEvent>,
+    mut level_failed_events: EventWriter<LevelFailedEvent>,
     time: Res<Time>,
 ) {
     if let Some(current_level) = level_data.get_current_level() {
@@ -529,7 +861,16 @@ fn check_level_completion(
             );
         }
 
-        if !level_session.target_reached && game_data.score >= current_level.target_points {
+        if level_session.is_in_progress() && current_level.time_limit.is_some_and(|limit| elapsed > limit) {
+            level_session.fail();
+
+            level_failed_events.write(LevelFailedEvent {
+                level_id: current_level.id,
+                reason: FailureReason::TimeOut,
+            });
+        }
+
+        if level_session.is_in_progress() && game_data.score >= current_level.target_points {
             level_session.complete();
 
             let completion_time = level_session.get_elapsed_time();
@@ -548,25 +889,33 @@ fn check_level_completion(
 /// Handle level-related events
 fn handle_level_events(
     mut level_complete_events: EventReader<LevelCompleteEvent>,
-    mut level_start_events: EventReader<LevelStartEvent>,
-    mut level_failed_events: EventReader<LevelFailedEvent>,
     mut level_data: ResMut<LevelData>,
-    mut level_session: ResMut<LevelSession>,
+    replay_playback: Option<Res<crate::replay::ReplayPlayback>>,
     _time: Res<Time>,
 ) {
+    // Replayed runs are only played back for verification, so they don't count towards progress
+    let replaying = crate::replay::is_replaying(replay_playback);
+
     // Handle level completions
     for event in level_complete_events.read() {
+        if replaying {
+            continue;
+        }
         level_data.complete_level(event.level_id, event.completion_time, event.final_score);
         info!("Level {} completed with {} stars!", event.level_id, event.stars_earned);
     }
+}
 
-    // Handle level starts
+/// Restart the level session before the first frame of a new level
+fn handle_level_starts(mut level_start_events: EventReader<LevelStartEvent>, mut level_session: ResMut<LevelSession>) {
     for event in level_start_events.read() {
         level_session.start();
         info!("Level {} started", event.level_id);
     }
+}
 
-    // Handle level failures
+/// Log level failures, whether from running out of time or quitting
+fn handle_level_failures(mut level_failed_events: EventReader<LevelFailedEvent>) {
     for event in level_failed_events.read() {
         info!("Level {} failed: {:?}", event.level_id, event.reason);
     }
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+pub mod actions;
+pub mod combos;
+pub mod enemies;
+pub mod headless;
+pub mod input;
+pub mod level_pack;
+pub mod levels;
+pub mod menu;
+pub mod menu_navigation;
+pub mod pause_menu;
+pub mod play_area;
+pub mod playing;
+pub mod powerups;
+pub mod replay;
+pub mod rng;
+pub mod save;
+pub mod spatial_grid;
+pub mod wind;
+
+/// Game states for managing different screens
+#[derive(States, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GameState {
+    Menu,
+    Playing,
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        Self::Menu
+    }
+}
+
+/// Marker component for sound entities that need cleanup
+#[derive(Component)]
+pub struct SoundEntity;
+
+/// Resource holding all preloaded asset handles
+#[derive(Resource, Clone, Default)]
+pub struct GameAssets {
+    pub bunny: Handle<Image>,
+    pub flamethrower: Handle<Image>,
+    pub weed_killer: Handle<Image>,
+    pub lawn_mower: Handle<Image>,
+    pub goose: Handle<Image>,
+    pub clover: Handle<Image>,
+    pub thistle_young: Handle<Image>,
+    pub thistle_flowering: Handle<Image>,
+    pub root: Handle<Image>,
+    pub dandelion_tiny: Handle<Image>,
+    pub dandelion_small: Handle<Image>,
+    pub dandelion_medium: Handle<Image>,
+    pub dandelion_large: Handle<Image>,
+    pub dandelion_huge: Handle<Image>,
+    pub dandelion_tiny_puffball: Handle<Image>,
+    pub dandelion_small_puffball: Handle<Image>,
+    pub dandelion_medium_puffball: Handle<Image>,
+    pub dandelion_large_puffball: Handle<Image>,
+    pub dandelion_huge_puffball: Handle<Image>,
+    pub dandelion: Handle<Image>,
+    pub seed: Handle<Image>,
+    pub star_complete: Handle<Image>,
+    pub star_incomplete: Handle<Image>,
+    pub slash_sound: Handle<AudioSource>,
+    // pub merge_sound: Handle<AudioSource>,
+    pub rabbit_sound: Handle<AudioSource>,
+    pub flamethrower_sound: Handle<AudioSource>,
+}
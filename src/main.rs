@@ -1,34 +1,22 @@
 use bevy::prelude::*;
 
-mod enemies;
-mod levels;
-mod menu;
-mod pause_menu;
-mod playing;
-mod powerups;
-use enemies::EnemiesPlugin;
-use levels::LevelsPlugin;
-use menu::MenuPlugin;
-use pause_menu::PauseMenuPlugin;
-use playing::PlayingPlugin;
-use powerups::PowerupsPlugin;
-
-/// Game states for managing different screens
-#[derive(States, Debug, Clone, PartialEq, Eq, Hash)]
-enum GameState {
-    Menu,
-    Playing,
-}
-
-impl Default for GameState {
-    fn default() -> Self {
-        Self::Menu
-    }
-}
-
-/// Marker component for sound entities that need cleanup
-#[derive(Component)]
-pub struct SoundEntity;
+use kill_all_dandelions::actions::ActionsPlugin;
+use kill_all_dandelions::combos::CombosPlugin;
+use kill_all_dandelions::enemies::EnemiesPlugin;
+use kill_all_dandelions::input::InputPlugin;
+use kill_all_dandelions::level_pack::{self, LevelPackPlugin};
+use kill_all_dandelions::levels::LevelsPlugin;
+use kill_all_dandelions::menu::MenuPlugin;
+use kill_all_dandelions::menu_navigation::MenuNavigationPlugin;
+use kill_all_dandelions::pause_menu::PauseMenuPlugin;
+use kill_all_dandelions::play_area::PlayAreaPlugin;
+use kill_all_dandelions::playing::PlayingPlugin;
+use kill_all_dandelions::powerups::PowerupsPlugin;
+use kill_all_dandelions::replay::{self, ReplayPlugin};
+use kill_all_dandelions::rng::{self, RngPlugin};
+use kill_all_dandelions::save::SavePlugin;
+use kill_all_dandelions::wind::WindPlugin;
+use kill_all_dandelions::{GameAssets, GameState, SoundEntity};
 
 // Resource for entity diagnostic logging timer
 // #[derive(Resource)]
@@ -45,6 +33,21 @@ pub struct SoundEntity;
 // }
 
 fn main() -> AppExit {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|arg| arg == "validate-levels") {
+        return level_pack::run_validate_levels(&args[1..]);
+    }
+
+    let (seed, replay) = match (rng::parse_seed_arg(&args), replay::load_replay_arg(&args)) {
+        (Ok(seed), Ok(replay)) => (seed, replay),
+        (Err(err), _) | (_, Err(err)) => {
+            eprintln!("{}", err);
+            return AppExit::error();
+        }
+    };
+    // A replay must run with the seed it was recorded with
+    let seed = replay.as_ref().map(|replay| replay.seed).or(seed);
+
     App::new()
         .add_plugins(
             DefaultPlugins
@@ -69,39 +72,50 @@ fn main() -> AppExit {
         .add_systems(Startup, preload_assets)
         .add_systems(OnExit(GameState::Playing), cleanup_sounds)
         // .add_systems(Update, log_entity_counts.run_if(in_state(GameState::Playing)))
-        .add_plugins((MenuPlugin, PauseMenuPlugin, PlayingPlugin, EnemiesPlugin, PowerupsPlugin, LevelsPlugin))
+        .add_plugins((
+            MenuPlugin,
+            MenuNavigationPlugin,
+            PauseMenuPlugin,
+            PlayingPlugin,
+            EnemiesPlugin,
+            PowerupsPlugin,
+            CombosPlugin,
+            LevelsPlugin,
+            LevelPackPlugin,
+            WindPlugin,
+        ))
+        .add_plugins((
+            ActionsPlugin,
+            InputPlugin,
+            PlayAreaPlugin,
+            SavePlugin,
+            RngPlugin { seed },
+            ReplayPlugin { replay },
+        ))
         .run()
 }
 
-/// Resource holding all preloaded asset handles
-#[derive(Resource, Clone)]
-pub struct GameAssets {
-    pub bunny: Handle<Image>,
-    pub flamethrower: Handle<Image>,
-    pub dandelion_tiny: Handle<Image>,
-    pub dandelion_small: Handle<Image>,
-    pub dandelion_medium: Handle<Image>,
-    pub dandelion_large: Handle<Image>,
-    pub dandelion_huge: Handle<Image>,
-    pub dandelion: Handle<Image>,
-    pub seed: Handle<Image>,
-    pub star_complete: Handle<Image>,
-    pub star_incomplete: Handle<Image>,
-    pub slash_sound: Handle<AudioSource>,
-    // pub merge_sound: Handle<AudioSource>,
-    pub rabbit_sound: Handle<AudioSource>,
-    pub flamethrower_sound: Handle<AudioSource>,
-}
-
 fn preload_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
     let assets = GameAssets {
         bunny: asset_server.load("bunny.png"),
         flamethrower: asset_server.load("flamethrower.png"),
+        weed_killer: asset_server.load("weed_killer.png"),
+        lawn_mower: asset_server.load("lawn_mower.png"),
+        goose: asset_server.load("goose.png"),
+        clover: asset_server.load("clover.png"),
+        thistle_young: asset_server.load("thistle_young.png"),
+        thistle_flowering: asset_server.load("thistle_flowering.png"),
+        root: asset_server.load("root.png"),
         dandelion_tiny: asset_server.load("dandelion_tiny.png"),
         dandelion_small: asset_server.load("dandelion_small.png"),
         dandelion_medium: asset_server.load("dandelion_medium.png"),
         dandelion_large: asset_server.load("dandelion_large.png"),
         dandelion_huge: asset_server.load("dandelion_huge.png"),
+        dandelion_tiny_puffball: asset_server.load("dandelion_tiny_puffball.png"),
+        dandelion_small_puffball: asset_server.load("dandelion_small_puffball.png"),
+        dandelion_medium_puffball: asset_server.load("dandelion_medium_puffball.png"),
+        dandelion_large_puffball: asset_server.load("dandelion_large_puffball.png"),
+        dandelion_huge_puffball: asset_server.load("dandelion_huge_puffball.png"),
         dandelion: asset_server.load("dandelion.png"),
         seed: asset_server.load("seed.png"),
         star_complete: asset_server.load("star_complete.png"),
@@ -1,8 +1,11 @@
 use bevy::prelude::*;
+use bevy_enhanced_input::prelude::Started;
 use rand::Rng;
 
-use crate::GameState;
+use crate::actions::{Back, Confirm};
 use crate::levels::{LevelData, LevelStartEvent};
+use crate::powerups::Upgrade;
+use crate::{GameAssets, GameState};
 
 /// Plugin for handling the main menu screen
 pub struct MenuPlugin;
@@ -13,9 +16,20 @@ impl Plugin for MenuPlugin {
             .add_systems(OnEnter(GameState::Menu), (setup_menu_camera, setup_menu_ui, reset_menu_state))
             .add_systems(OnEnter(MenuState::Credits), setup_credits_menu)
             .add_systems(OnExit(MenuState::Credits), cleanup_credits_menu)
+            .add_systems(OnEnter(MenuState::Upgrades), setup_upgrades_menu)
+            .add_systems(OnExit(MenuState::Upgrades), cleanup_upgrades_menu)
             .add_systems(Update, handle_menu_input.run_if(in_state(GameState::Menu)))
+            .add_systems(
+                Update,
+                (handle_upgrade_input, update_upgrade_rows)
+                    .chain()
+                    .run_if(in_state(GameState::Menu))
+                    .run_if(in_state(MenuState::Upgrades)),
+            )
             .add_systems(Update, update_dynamic_font_sizes.run_if(in_state(GameState::Menu)))
-            .add_systems(OnExit(GameState::Menu), cleanup_menu);
+            .add_systems(OnExit(GameState::Menu), cleanup_menu)
+            .add_observer(confirm_menu)
+            .add_observer(back_to_main_menu);
     }
 }
 
@@ -23,6 +37,7 @@ impl Plugin for MenuPlugin {
 #[derive(States, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MenuState {
     Main,
+    Upgrades,
     Credits,
 }
 
@@ -40,6 +55,7 @@ struct MenuEntity;
 #[derive(Component)]
 enum MenuButton {
     Play,
+    Upgrades,
     Credits,
 }
 
@@ -158,6 +174,31 @@ fn setup_menu_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ));
                 });
 
+            // Upgrades button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Vw(35.0),
+                        height: Val::Vh(8.0),
+                        margin: UiRect::all(Val::Vh(1.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.6, 0.5, 0.2)),
+                    MenuButton::Upgrades,
+                    MenuEntity,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Upgrades"),
+                        TextFont { font_size: 22.0, ..default() },
+                        TextColor(Color::WHITE),
+                        DynamicFontSize { base_size: 22.0 },
+                    ));
+                });
+
             // Credits button
             parent
                 .spawn((
@@ -357,7 +398,7 @@ fn setup_credits_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                             },
                             BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
                             BorderRadius::all(Val::Px(5.0)),
-                            CreditsBackButton,
+                            MenuBackButton,
                         ))
                         .with_children(|parent| {
                             parent.spawn((
@@ -371,8 +412,227 @@ fn setup_credits_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
         });
 }
 
+/// Back button shared by the credits and upgrades screens
+#[derive(Component)]
+struct MenuBackButton;
+
+#[derive(Component)]
+struct UpgradesMenuEntity;
+
+/// Button that buys the next level of an upgrade
+#[derive(Component)]
+struct UpgradeButton(Upgrade);
+
+/// Text showing an upgrade's level and current value
 #[derive(Component)]
-struct CreditsBackButton;
+struct UpgradeLevelText(Upgrade);
+
+/// Text on an upgrade's buy button
+#[derive(Component)]
+struct UpgradeCostText(Upgrade);
+
+/// Text showing how many stars are left to spend
+#[derive(Component)]
+struct UnspentStarsText;
+
+/// Setup the upgrades screen, one row per upgrade
+fn setup_upgrades_menu(mut commands: Commands, assets: Res<GameAssets>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            UpgradesMenuEntity,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Vw(80.0),
+                        max_width: Val::Px(640.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Vh(2.5)),
+                        row_gap: Val::Vh(1.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                    BorderRadius::all(Val::Px(10.0)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Upgrades"),
+                        TextFont { font_size: 28.0, ..default() },
+                        TextColor(Color::WHITE),
+                        DynamicFontSize { base_size: 28.0 },
+                    ));
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont { font_size: 16.0, ..default() },
+                        TextColor(Color::srgb(0.9, 0.9, 0.5)),
+                        DynamicFontSize { base_size: 16.0 },
+                        UnspentStarsText,
+                    ));
+
+                    for upgrade in Upgrade::all() {
+                        parent
+                            .spawn((
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    flex_direction: FlexDirection::Row,
+                                    align_items: AlignItems::Center,
+                                    column_gap: Val::Vw(2.0),
+                                    padding: UiRect::all(Val::Vh(0.8)),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                                BorderRadius::all(Val::Px(5.0)),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    ImageNode::new(upgrade.powerup_type().image(&assets)),
+                                    Node {
+                                        width: Val::Px(36.0),
+                                        height: Val::Px(36.0),
+                                        ..default()
+                                    },
+                                ));
+
+                                parent
+                                    .spawn((Node {
+                                        flex_direction: FlexDirection::Column,
+                                        flex_grow: 1.0,
+                                        ..default()
+                                    },))
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            Text::new(upgrade.name()),
+                                            TextFont { font_size: 16.0, ..default() },
+                                            TextColor(Color::srgb(0.9, 0.9, 0.5)),
+                                            DynamicFontSize { base_size: 16.0 },
+                                        ));
+                                        parent.spawn((
+                                            Text::new(""),
+                                            TextFont { font_size: 13.0, ..default() },
+                                            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                                            DynamicFontSize { base_size: 13.0 },
+                                            UpgradeLevelText(upgrade),
+                                        ));
+                                    });
+
+                                parent
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            width: Val::Px(100.0),
+                                            height: Val::Px(36.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        BackgroundColor(Color::srgb(0.25, 0.25, 0.25)),
+                                        BorderRadius::all(Val::Px(5.0)),
+                                        UpgradeButton(upgrade),
+                                    ))
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            Text::new(""),
+                                            TextFont { font_size: 14.0, ..default() },
+                                            TextColor(Color::WHITE),
+                                            DynamicFontSize { base_size: 14.0 },
+                                            UpgradeCostText(upgrade),
+                                        ));
+                                    });
+                            });
+                    }
+
+                    // Back button
+                    parent
+                        .spawn((
+                            Button,
+                            Node {
+                                width: Val::Vw(30.0),
+                                max_width: Val::Px(200.0),
+                                height: Val::Vh(7.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                margin: UiRect::top(Val::Vh(1.5)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+                            BorderRadius::all(Val::Px(5.0)),
+                            MenuBackButton,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new("Back"),
+                                TextFont { font_size: 20.0, ..default() },
+                                TextColor(Color::WHITE),
+                                DynamicFontSize { base_size: 20.0 },
+                            ));
+                        });
+                });
+        });
+}
+
+/// Buy an upgrade when its button is pressed, saving straight away
+fn handle_upgrade_input(button_query: Query<(&Interaction, &UpgradeButton), Changed<Interaction>>, mut level_data: ResMut<LevelData>) {
+    for (interaction, button) in &button_query {
+        if *interaction == Interaction::Pressed && level_data.buy_upgrade(button.0) {
+            info!("Bought {} level {}", button.0.name(), level_data.upgrades.level(button.0));
+            crate::save::save_level_data(&level_data);
+        }
+    }
+}
+
+/// Refresh upgrade levels, prices and button colors
+fn update_upgrade_rows(
+    level_data: Res<LevelData>,
+    mut buttons: Query<(Ref<Interaction>, &UpgradeButton, &mut BackgroundColor)>,
+    mut texts: ParamSet<(
+        Query<&mut Text, With<UnspentStarsText>>,
+        Query<(&mut Text, &UpgradeLevelText)>,
+        Query<(&mut Text, &UpgradeCostText)>,
+    )>,
+) {
+    let stars = level_data.unspent_stars();
+    let upgrades = &level_data.upgrades;
+
+    for (interaction, button, mut color) in &mut buttons {
+        if !level_data.is_changed() && !interaction.is_changed() {
+            continue;
+        }
+        let affordable = upgrades.next_cost(button.0).is_some_and(|cost| cost <= stars);
+        *color = BackgroundColor(match (affordable, *interaction) {
+            (true, Interaction::None) => Color::srgb(0.3, 0.6, 0.3),
+            (true, _) => Color::srgb(0.4, 0.7, 0.4),
+            (false, _) => Color::srgb(0.25, 0.25, 0.25),
+        });
+    }
+
+    if !level_data.is_changed() {
+        return;
+    }
+    for mut text in &mut texts.p0() {
+        text.0 = format!("Stars to spend: {}", stars);
+    }
+    for (mut text, row) in &mut texts.p1() {
+        text.0 = format!("Level {}/{} - {}", upgrades.level(row.0), row.0.max_level(), upgrades.describe(row.0));
+    }
+    for (mut text, cost) in &mut texts.p2() {
+        text.0 = match upgrades.next_cost(cost.0) {
+            Some(1) => "1 star".to_string(),
+            Some(price) => format!("{} stars", price),
+            None => "Maxed".to_string(),
+        };
+    }
+}
 
 /// Reset menu state to main when entering menu
 fn reset_menu_state(mut next_menu_state: ResMut<NextState<MenuState>>) {
@@ -381,12 +641,11 @@ fn reset_menu_state(mut next_menu_state: ResMut<NextState<MenuState>>) {
 
 /// Handle menu input and button interactions
 fn handle_menu_input(
-    mut main_button_query: Query<(&Interaction, &mut BackgroundColor, &MenuButton), (Changed<Interaction>, With<Button>, Without<CreditsBackButton>)>,
-    mut credits_button_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<CreditsBackButton>)>,
+    mut main_button_query: Query<(&Interaction, &mut BackgroundColor, &MenuButton), (Changed<Interaction>, With<Button>, Without<MenuBackButton>)>,
+    mut back_button_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<MenuBackButton>)>,
     mut next_game_state: ResMut<NextState<GameState>>,
     mut next_menu_state: ResMut<NextState<MenuState>>,
     current_menu_state: Res<State<MenuState>>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
     mut level_data: ResMut<LevelData>,
     mut level_start_events: EventWriter<LevelStartEvent>,
 ) {
@@ -396,40 +655,30 @@ fn handle_menu_input(
             for (interaction, mut color, button_type) in &mut main_button_query {
                 match *interaction {
                     Interaction::Pressed => match button_type {
-                        MenuButton::Play => {
-                            // Set the current level to level 1 and emit start event
-                            level_data.set_current_level(1);
-                            level_start_events.write(LevelStartEvent { level_id: 1 });
-                            next_game_state.set(GameState::Playing);
-                        }
+                        MenuButton::Play => start_first_level(&mut level_data, &mut level_start_events, &mut next_game_state),
+                        MenuButton::Upgrades => next_menu_state.set(MenuState::Upgrades),
                         MenuButton::Credits => next_menu_state.set(MenuState::Credits),
                     },
                     Interaction::Hovered => {
                         *color = match button_type {
                             MenuButton::Play => BackgroundColor(Color::srgb(0.4, 0.8, 0.4)),
+                            MenuButton::Upgrades => BackgroundColor(Color::srgb(0.7, 0.6, 0.3)),
                             MenuButton::Credits => BackgroundColor(Color::srgb(0.5, 0.5, 0.7)),
                         };
                     }
                     Interaction::None => {
                         *color = match button_type {
                             MenuButton::Play => BackgroundColor(Color::srgb(0.3, 0.7, 0.3)),
+                            MenuButton::Upgrades => BackgroundColor(Color::srgb(0.6, 0.5, 0.2)),
                             MenuButton::Credits => BackgroundColor(Color::srgb(0.4, 0.4, 0.6)),
                         };
                     }
                 }
             }
-
-            // Handle keyboard input only in main menu
-            if keyboard_input.just_pressed(KeyCode::Space) || keyboard_input.just_pressed(KeyCode::Enter) {
-                // Set the current level to level 1 and emit start event
-                level_data.set_current_level(1);
-                level_start_events.write(LevelStartEvent { level_id: 1 });
-                next_game_state.set(GameState::Playing);
-            }
         }
-        MenuState::Credits => {
-            // Handle credits back button only when in credits state
-            for (interaction, mut color) in &mut credits_button_query {
+        MenuState::Upgrades | MenuState::Credits => {
+            // Handle the back button only when on a sub-screen
+            for (interaction, mut color) in &mut back_button_query {
                 match *interaction {
                     Interaction::Pressed => {
                         next_menu_state.set(MenuState::Main);
@@ -446,6 +695,33 @@ fn handle_menu_input(
     }
 }
 
+/// Start the game when the Confirm action is pressed on the main menu
+fn confirm_menu(
+    _trigger: Trigger<Started<Confirm>>,
+    current_menu_state: Res<State<MenuState>>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut level_data: ResMut<LevelData>,
+    mut level_start_events: EventWriter<LevelStartEvent>,
+) {
+    if *current_menu_state.get() == MenuState::Main {
+        start_first_level(&mut level_data, &mut level_start_events, &mut next_game_state);
+    }
+}
+
+/// Leave the credits or upgrades when the Back action is pressed
+fn back_to_main_menu(_trigger: Trigger<Started<Back>>, current_menu_state: Res<State<MenuState>>, mut next_menu_state: ResMut<NextState<MenuState>>) {
+    if *current_menu_state.get() != MenuState::Main {
+        next_menu_state.set(MenuState::Main);
+    }
+}
+
+/// Set the current level to level 1 and emit its start event
+fn start_first_level(level_data: &mut LevelData, level_start_events: &mut EventWriter<LevelStartEvent>, next_game_state: &mut NextState<GameState>) {
+    level_data.set_current_level(1);
+    level_start_events.write(LevelStartEvent { level_id: 1 });
+    next_game_state.set(GameState::Playing);
+}
+
 /// Cleanup menu entities when exiting menu state
 fn cleanup_menu(mut commands: Commands, menu_entities: Query<Entity, With<MenuEntity>>) {
     for entity in &menu_entities {
@@ -460,6 +736,13 @@ fn cleanup_credits_menu(mut commands: Commands, credits_entities: Query<Entity,
     }
 }
 
+/// Cleanup upgrades menu entities
+fn cleanup_upgrades_menu(mut commands: Commands, upgrades_entities: Query<Entity, With<UpgradesMenuEntity>>) {
+    for entity in &upgrades_entities {
+        commands.entity(entity).despawn();
+    }
+}
+
 /// Get a random subtitle for the main menu
 fn get_random_subtitle() -> &'static str {
     const SUBTITLES: &[&str] = &[
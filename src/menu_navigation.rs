@@ -0,0 +1,148 @@
+use bevy::prelude::*;
+use bevy::ui::{UiStack, UiSystem};
+use bevy_enhanced_input::prelude::*;
+
+use crate::actions::{MenuInput, Navigate, PausedInput, Select};
+
+/// Plugin that lets the d-pad and arrow keys move between the buttons of the main and pause menus
+pub struct MenuNavigationPlugin;
+
+impl Plugin for MenuNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        // After UI focus, so a selected button reads as pressed by the interaction systems in `Update`
+        app.init_resource::<MenuFocus>()
+            .add_systems(
+                PreUpdate,
+                (navigate_menu, select_focused_button).chain().after(EnhancedInputSystem).after(UiSystem::Focus),
+            )
+            .add_systems(PostUpdate, outline_focused_button);
+    }
+}
+
+/// Button focused by menu navigation, if any
+#[derive(Resource, Default)]
+pub struct MenuFocus(pub Option<Entity>);
+
+/// This frame's events and value for action `A` from whichever menu context is active
+fn menu_action<A: InputAction>(
+    menu_actions: &Query<&Actions<MenuInput>>,
+    paused_actions: &Query<&Actions<PausedInput>>,
+) -> Option<(ActionEvents, ActionValue)> {
+    let action = match (menu_actions.single(), paused_actions.single()) {
+        (Ok(actions), _) => actions.get::<A>().ok(),
+        (_, Ok(actions)) => actions.get::<A>().ok(),
+        _ => None,
+    }?;
+    Some((action.events(), action.value()))
+}
+
+/// Screen-space centers of the buttons on the topmost menu, so buttons covered by an overlay are skipped
+fn navigable_buttons(
+    buttons: &Query<(Entity, &GlobalTransform, &InheritedVisibility), With<Button>>,
+    parents: &Query<&ChildOf>,
+    ui_stack: Option<&UiStack>,
+) -> Vec<(Entity, Vec2)> {
+    let stack_index = |entity: Entity| ui_stack.and_then(|ui_stack| ui_stack.uinodes.iter().position(|&node| node == entity));
+
+    let visible: Vec<(Entity, Vec2, Option<usize>)> = buttons
+        .iter()
+        .filter(|(_, _, visibility)| visibility.get())
+        .map(|(entity, transform, _)| (entity, transform.translation().truncate(), stack_index(parents.root_ancestor(entity))))
+        .collect();
+    let top_root = visible.iter().map(|(_, _, root)| *root).max().flatten();
+
+    visible
+        .into_iter()
+        .filter(|(_, _, root)| *root == top_root)
+        .map(|(entity, center, _)| (entity, center))
+        .collect()
+}
+
+/// Move focus to the nearest button in the pressed direction, or to the first button if nothing is focused
+fn navigate_menu(
+    menu_actions: Query<&Actions<MenuInput>>,
+    paused_actions: Query<&Actions<PausedInput>>,
+    buttons: Query<(Entity, &GlobalTransform, &InheritedVisibility), With<Button>>,
+    parents: Query<&ChildOf>,
+    ui_stack: Option<Res<UiStack>>,
+    mut focus: ResMut<MenuFocus>,
+) {
+    let Some((events, value)) = menu_action::<Navigate>(&menu_actions, &paused_actions) else {
+        return;
+    };
+    if !events.contains(ActionEvents::STARTED) {
+        return;
+    }
+
+    let candidates = navigable_buttons(&buttons, &parents, ui_stack.as_deref());
+    let focused = focus.0.and_then(|focused| candidates.iter().find(|(entity, _)| *entity == focused));
+    let Some(&(focused, from)) = focused else {
+        // Top-left button first
+        focus.0 = candidates
+            .iter()
+            .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)))
+            .map(|(entity, _)| *entity);
+        return;
+    };
+
+    // UI y grows downwards
+    let direction = value.as_axis2d() * Vec2::new(1.0, -1.0);
+    let next = candidates
+        .iter()
+        .filter(|(entity, _)| *entity != focused)
+        .filter_map(|&(entity, center)| {
+            let offset = center - from;
+            let along = offset.dot(direction);
+            // Prefer buttons straight ahead over ones further to the side
+            (along > 0.0).then(|| (entity, along + (offset - direction * along).length() * 2.0))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    if let Some((entity, _)) = next {
+        focus.0 = Some(entity);
+    }
+}
+
+/// Press the focused button, through the same `Interaction` the mouse would set
+fn select_focused_button(
+    menu_actions: Query<&Actions<MenuInput>>,
+    paused_actions: Query<&Actions<PausedInput>>,
+    focus: Res<MenuFocus>,
+    mut interactions: Query<&mut Interaction, With<Button>>,
+) {
+    let Some((events, _)) = menu_action::<Select>(&menu_actions, &paused_actions) else {
+        return;
+    };
+    if !events.contains(ActionEvents::STARTED) {
+        return;
+    }
+
+    if let Some(mut interaction) = focus.0.and_then(|focused| interactions.get_mut(focused).ok()) {
+        *interaction = Interaction::Pressed;
+    }
+}
+
+/// Outline the focused button and clear the outline from the one that lost focus
+fn outline_focused_button(mut commands: Commands, mut focus: ResMut<MenuFocus>, outlined: Query<Entity, With<FocusOutline>>, buttons: Query<(), With<Button>>) {
+    // The focused button went away with its screen
+    if focus.0.is_some_and(|focused| !buttons.contains(focused)) {
+        focus.0 = None;
+    }
+
+    for entity in &outlined {
+        if Some(entity) != focus.0 {
+            commands.entity(entity).remove::<(Outline, FocusOutline)>();
+        }
+    }
+    if let Some(focused) = focus.0
+        && !outlined.contains(focused)
+    {
+        commands
+            .entity(focused)
+            .insert((Outline::new(Val::Px(3.0), Val::Px(2.0), Color::WHITE), FocusOutline));
+    }
+}
+
+/// Marks the outline added by menu navigation
+#[derive(Component)]
+struct FocusOutline;
@@ -7,9 +7,11 @@ use bevy::{
     prelude::*,
     ui::ScrollPosition,
 };
+use bevy_enhanced_input::prelude::{Input, RebuildBindings, Started};
 
 use crate::GameState;
-use crate::levels::{LevelData, LevelStartEvent};
+use crate::actions::{ActionBindings, Back, BindableAction};
+use crate::levels::{FailureReason, LevelData, LevelFailedEvent, LevelSession, LevelStartEvent};
 
 /// Plugin for handling the pause menu
 pub struct PauseMenuPlugin;
@@ -19,28 +21,37 @@ impl Plugin for PauseMenuPlugin {
         app.init_state::<PauseState>()
             .init_state::<PauseMenuState>()
             .init_resource::<TouchScrollState>()
+            .init_resource::<PendingRebind>()
             .add_systems(OnEnter(PauseState::Paused), (setup_pause_menu_on_pause, pause_sounds))
             .add_systems(OnExit(PauseState::Paused), (cleanup_pause_menu, resume_sounds))
             .add_systems(
                 Update,
-                (handle_pause_input, pause_menu_interactions).run_if(in_state(PauseState::Paused).and(in_state(PauseMenuState::PauseMenu))),
+                pause_menu_interactions.run_if(in_state(PauseState::Paused).and(in_state(PauseMenuState::PauseMenu))),
             )
             .add_systems(
                 Update,
-                (handle_powerup_help_input, powerup_help_interactions).run_if(in_state(PauseState::Paused).and(in_state(PauseMenuState::PowerupHelp))),
+                powerup_help_interactions.run_if(in_state(PauseState::Paused).and(in_state(PauseMenuState::PowerupHelp))),
             )
             .add_systems(
                 Update,
-                (handle_level_selection_input, level_selection_interactions, update_star_displays)
-                    .run_if(in_state(PauseState::Paused).and(in_state(PauseMenuState::LevelSelection))),
+                (level_selection_interactions, update_star_displays).run_if(in_state(PauseState::Paused).and(in_state(PauseMenuState::LevelSelection))),
             )
+            .add_systems(
+                Update,
+                // Capture before the buttons react, so the click that starts a rebind isn't taken as the new binding
+                (capture_rebind_input, controls_interactions, update_binding_texts)
+                    .chain()
+                    .run_if(in_state(PauseState::Paused).and(in_state(PauseMenuState::Controls))),
+            )
+            .add_systems(OnExit(PauseState::Paused), cancel_pending_rebind)
             .add_systems(Update, switch_pause_menu_content.run_if(in_state(PauseState::Paused)))
             .add_systems(Update, update_dynamic_font_sizes)
             .add_systems(Update, update_scroll_position)
             .add_systems(
                 Update,
                 handle_touch_scroll.run_if(in_state(PauseState::Paused).and(in_state(PauseMenuState::LevelSelection))),
-            );
+            )
+            .add_observer(go_back);
     }
 }
 
@@ -57,6 +68,7 @@ pub enum PauseMenuState {
     PauseMenu,
     PowerupHelp,
     LevelSelection,
+    Controls,
 }
 
 impl Default for PauseState {
@@ -82,6 +94,7 @@ enum PauseMenuButton {
     Restart,
     PowerupHelp,
     LevelSelection,
+    Controls,
 }
 
 /// Powerup help menu button types
@@ -97,6 +110,22 @@ enum LevelSelectionButton {
     LevelButton(u32),
 }
 
+/// Controls menu button types
+#[derive(Component)]
+enum ControlsButton {
+    Back,
+    ResetDefaults,
+    Rebind(BindableAction),
+}
+
+/// Text listing the inputs bound to an action on the controls menu
+#[derive(Component)]
+struct BindingText(BindableAction);
+
+/// Action waiting for the player to press its new input on the controls menu
+#[derive(Resource, Default)]
+struct PendingRebind(Option<BindableAction>);
+
 /// Component for star display in level selection
 #[derive(Component)]
 struct StarDisplay {
@@ -122,10 +151,22 @@ struct DynamicFontSize {
     base_size: f32,
 }
 
-/// Handle input while paused
-fn handle_pause_input(keyboard_input: Res<ButtonInput<KeyCode>>, mut next_pause_state: ResMut<NextState<PauseState>>) {
-    if keyboard_input.just_pressed(KeyCode::KeyQ) {
-        next_pause_state.set(PauseState::Playing);
+/// Step back out of the pause menu when the Back action is pressed: sub-screens return to the pause menu, which resumes the game
+fn go_back(
+    _trigger: Trigger<Started<Back>>,
+    pause_menu_state: Res<State<PauseMenuState>>,
+    pending_rebind: Res<PendingRebind>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+    mut next_pause_menu_state: ResMut<NextState<PauseMenuState>>,
+) {
+    // The key is being captured as a new binding
+    if pending_rebind.0.is_some() {
+        return;
+    }
+
+    match pause_menu_state.get() {
+        PauseMenuState::PauseMenu => next_pause_state.set(PauseState::Playing),
+        PauseMenuState::PowerupHelp | PauseMenuState::LevelSelection | PauseMenuState::Controls => next_pause_menu_state.set(PauseMenuState::PauseMenu),
     }
 }
 
@@ -150,8 +191,8 @@ fn setup_pause_menu(mut commands: Commands) {
                     Node {
                         width: Val::Vw(60.0),
                         max_width: Val::Px(400.0),
-                        height: Val::Vh(60.0),
-                        max_height: Val::Px(350.0),
+                        height: Val::Vh(70.0),
+                        max_height: Val::Px(420.0),
                         flex_direction: FlexDirection::Column,
                         align_items: AlignItems::Center,
                         justify_content: JustifyContent::Center,
@@ -269,6 +310,31 @@ fn setup_pause_menu(mut commands: Commands) {
                                 DynamicFontSize { base_size: 18.0 },
                             ));
                         });
+
+                    parent
+                        .spawn((
+                            Button,
+                            Node {
+                                width: Val::Vw(40.0),
+                                max_width: Val::Px(250.0),
+                                height: Val::Vh(7.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.5, 0.4, 0.6)),
+                            BorderRadius::all(Val::Px(5.0)),
+                            PauseMenuButton::Controls,
+                            PauseMenuEntity,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Text::new("Controls"),
+                                TextFont { font_size: 18.0, ..default() },
+                                TextColor(Color::WHITE),
+                                DynamicFontSize { base_size: 18.0 },
+                            ));
+                        });
                 });
         });
 }
@@ -280,6 +346,8 @@ fn pause_menu_interactions(
     mut next_pause_menu_state: ResMut<NextState<PauseMenuState>>,
     mut next_game_state: ResMut<NextState<GameState>>,
     mut level_data: ResMut<LevelData>,
+    level_session: Option<Res<LevelSession>>,
+    mut level_failed_events: EventWriter<LevelFailedEvent>,
 ) {
     for (interaction, mut color, button_type) in &mut interaction_query {
         match *interaction {
@@ -288,8 +356,11 @@ fn pause_menu_interactions(
                     next_pause_state.set(PauseState::Playing);
                 }
                 PauseMenuButton::Restart => {
+                    quit_current_level(&level_data, level_session.as_deref(), &mut level_failed_events);
+
                     // Reset all level progress before returning to menu
                     level_data.reset_all_progress();
+                    crate::save::save_level_data(&level_data);
                     next_pause_state.set(PauseState::Playing);
                     next_game_state.set(GameState::Menu);
                 }
@@ -299,23 +370,38 @@ fn pause_menu_interactions(
                 PauseMenuButton::LevelSelection => {
                     next_pause_menu_state.set(PauseMenuState::LevelSelection);
                 }
+                PauseMenuButton::Controls => {
+                    next_pause_menu_state.set(PauseMenuState::Controls);
+                }
             },
             Interaction::Hovered => match button_type {
                 PauseMenuButton::Resume => *color = BackgroundColor(Color::srgb(0.5, 0.5, 0.5)),
                 PauseMenuButton::Restart => *color = BackgroundColor(Color::srgb(0.8, 0.4, 0.4)),
                 PauseMenuButton::PowerupHelp => *color = BackgroundColor(Color::srgb(0.4, 0.6, 0.8)),
                 PauseMenuButton::LevelSelection => *color = BackgroundColor(Color::srgb(0.6, 0.8, 0.4)),
+                PauseMenuButton::Controls => *color = BackgroundColor(Color::srgb(0.7, 0.6, 0.8)),
             },
             Interaction::None => match button_type {
                 PauseMenuButton::Resume => *color = BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
                 PauseMenuButton::Restart => *color = BackgroundColor(Color::srgb(0.6, 0.3, 0.3)),
                 PauseMenuButton::PowerupHelp => *color = BackgroundColor(Color::srgb(0.3, 0.5, 0.7)),
                 PauseMenuButton::LevelSelection => *color = BackgroundColor(Color::srgb(0.4, 0.6, 0.2)),
+                PauseMenuButton::Controls => *color = BackgroundColor(Color::srgb(0.5, 0.4, 0.6)),
             },
         }
     }
 }
 
+/// Report the level in progress as abandoned when leaving it from the pause menu
+fn quit_current_level(level_data: &LevelData, level_session: Option<&LevelSession>, level_failed_events: &mut EventWriter<LevelFailedEvent>) {
+    if level_session.is_some_and(LevelSession::is_in_progress) {
+        level_failed_events.write(LevelFailedEvent {
+            level_id: level_data.current_level,
+            reason: FailureReason::PlayerQuit,
+        });
+    }
+}
+
 /// Cleanup pause menu entities
 fn cleanup_pause_menu(mut commands: Commands, pause_entities: Query<Entity, With<PauseMenuEntity>>) {
     for entity in &pause_entities {
@@ -323,13 +409,6 @@ fn cleanup_pause_menu(mut commands: Commands, pause_entities: Query<Entity, With
     }
 }
 
-/// Handle input while in powerup help screen
-fn handle_powerup_help_input(keyboard_input: Res<ButtonInput<KeyCode>>, mut next_pause_menu_state: ResMut<NextState<PauseMenuState>>) {
-    if keyboard_input.just_pressed(KeyCode::KeyQ) {
-        next_pause_menu_state.set(PauseMenuState::PauseMenu);
-    }
-}
-
 /// Setup powerup help menu UI
 fn setup_powerup_help_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands
@@ -383,126 +462,42 @@ fn setup_powerup_help_menu(mut commands: Commands, asset_server: Res<AssetServer
                             row_gap: Val::Vh(1.5),
                             ..default()
                         },))                        .with_children(|parent| {
-                            // Bunny powerup row
-                            parent
-                                .spawn((
-                                    Node {
-                                        width: Val::Percent(100.0),
-                                        min_height: Val::Vh(10.0),
-                                        flex_direction: FlexDirection::Row,
-                                        align_items: AlignItems::Center,
-                                        column_gap: Val::Vw(3.0),
-                                        padding: UiRect::all(Val::Vh(1.5)),
-                                        ..default()
-                                    },
-                                    BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
-                                    BorderRadius::all(Val::Px(5.0)),
-                                ))
-                                .with_children(|parent| {
-                                    // Powerup icon
-                                    parent.spawn((
-                                        ImageNode::new(asset_server.load("bunny.png")),
-                                        Node {
-                                            width: Val::Vw(8.0),
-                                            height: Val::Vw(8.0),
-                                            max_width: Val::Px(60.0),
-                                            max_height: Val::Px(60.0),
-                                            ..default()
-                                        },
-                                    ));
-
-                                    // Description text
-                                    parent
-                                        .spawn((
-                                            Node {
-                                                flex_direction: FlexDirection::Column,
-                                                flex_grow: 1.0,
-                                                row_gap: Val::Vh(0.5),
-                                                ..default()
-                                            },
-                                        ))
-                                        .with_children(|parent| {
-                                            parent.spawn((
-                                                Text::new("Bunny"),
-                                                TextFont {
-                                                    font_size: 16.0,
-                                                    ..default()
-                                                },
-                                                TextColor(Color::srgb(0.9, 0.9, 0.5)),
-                                                DynamicFontSize { base_size: 16.0 },
-                                            ));
-
-                                            parent.spawn((
-                                                Text::new("Spawns 3 rabbits that seek and destroy dandelions. Each rabbit has 3 seconds to eat a dandelion, and eating a least 2 spawns a new rabbit."),
-                                                TextFont {
-                                                    font_size: 14.0,
-                                                    ..default()
-                                                },
-                                                TextColor(Color::srgb(0.8, 0.8, 0.8)),
-                                                DynamicFontSize { base_size: 14.0 },
-                                            ));
-                                        });
-                                });
-                            // Flamethrower powerup row
-                            parent
-                                .spawn((
-                                    Node {
-                                        width: Val::Percent(100.0),
-                                        min_height: Val::Vh(10.0),
-                                        flex_direction: FlexDirection::Row,
-                                        align_items: AlignItems::Center,
-                                        column_gap: Val::Vw(3.0),
-                                        padding: UiRect::all(Val::Vh(1.5)),
-                                        ..default()
-                                    },
-                                    BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
-                                    BorderRadius::all(Val::Px(5.0)),
-                                ))
-                                .with_children(|parent| {
-                                    // Powerup icon
-                                    parent.spawn((
-                                        ImageNode::new(asset_server.load("flamethrower.png")),
-                                        Node {
-                                            width: Val::Vw(8.0),
-                                            height: Val::Vw(8.0),
-                                            max_width: Val::Px(60.0),
-                                            max_height: Val::Px(60.0),
-                                            ..default()
-                                        },
-                                    ));
-
-                                    // Description text
-                                    parent
-                                        .spawn((
-                                            Node {
-                                                flex_direction: FlexDirection::Column,
-                                                flex_grow: 1.0,
-                                                row_gap: Val::Vh(0.5),
-                                                ..default()
-                                            },
-                                        ))
-                                        .with_children(|parent| {
-                                            parent.spawn((
-                                                Text::new("Flamethrower"),
-                                                TextFont {
-                                                    font_size: 18.0,
-                                                    ..default()
-                                                },
-                                                TextColor(Color::srgb(0.9, 0.9, 0.5)),
-                                                DynamicFontSize { base_size: 18.0 },
-                                            ));
-
-                                            parent.spawn((
-                                                Text::new("Creates a fire ignition that continuously damages all dandelions within its radius for 2 seconds. Effective against groups of dandelions."),
-                                                TextFont {
-                                                    font_size: 14.0,
-                                                    ..default()
-                                                },
-                                                TextColor(Color::srgb(0.8, 0.8, 0.8)),
-                                                DynamicFontSize { base_size: 14.0 },
-                                            ));
-                                        });
-                                });
+                            spawn_powerup_help_row(
+                                parent,
+                                asset_server.load("bunny.png"),
+                                "Bunny",
+                                "Spawns 3 rabbits that seek and destroy dandelions. Each rabbit has 3 seconds to eat a dandelion, and eating a least 2 spawns a new rabbit. Rabbits run from fire.",
+                            );
+                            spawn_powerup_help_row(
+                                parent,
+                                asset_server.load("flamethrower.png"),
+                                "Flamethrower",
+                                "Creates a fire ignition that continuously damages all dandelions within its radius for 2 seconds. Effective against groups of dandelions. Fire lit where a rabbit just ate spreads one step further.",
+                            );
+                            spawn_powerup_help_row(
+                                parent,
+                                asset_server.load("weed_killer.png"),
+                                "Weed Killer",
+                                "Sprays a poison zone that damages every dandelion inside it for 6 seconds. Seeds that land in the zone never sprout. Spraying over fire puts it out.",
+                            );
+                            spawn_powerup_help_row(
+                                parent,
+                                asset_server.load("lawn_mower.png"),
+                                "Lawn Mower",
+                                "Drives across the lawn from where it starts, heavily damaging every dandelion in its lane and cutting down seeds in flight.",
+                            );
+                            spawn_powerup_help_row(
+                                parent,
+                                asset_server.load("goose.png"),
+                                "Goose",
+                                "Releases 2 geese that catch seeds in flight before they can sprout, each chasing its own seed. They stay for 8 seconds.",
+                            );
+                            spawn_powerup_help_row(
+                                parent,
+                                asset_server.load("thistle_flowering.png"),
+                                "Thistle",
+                                "Not a powerup, but watch out for it in later levels. Clicking a thistle resets your combo, so switch to slash mode to cut it down safely. It scatters no seeds and is worth double points.",
+                            );
                         });
 
                     // Back button
@@ -781,13 +776,6 @@ fn update_star_displays(mut star_query: Query<(&mut ImageNode, &StarDisplay)>, l
     }
 }
 
-/// Handle input while in level selection screen
-fn handle_level_selection_input(keyboard_input: Res<ButtonInput<KeyCode>>, mut next_pause_menu_state: ResMut<NextState<PauseMenuState>>) {
-    if keyboard_input.just_pressed(KeyCode::KeyQ) {
-        next_pause_menu_state.set(PauseMenuState::PauseMenu);
-    }
-}
-
 /// Handle level selection button interactions
 fn level_selection_interactions(
     mut interaction_query: Query<(&Interaction, &mut BackgroundColor, &LevelSelectionButton), (Changed<Interaction>, With<Button>)>,
@@ -796,6 +784,8 @@ fn level_selection_interactions(
     mut next_game_state: ResMut<NextState<GameState>>,
     mut level_start_events: EventWriter<LevelStartEvent>,
     level_data: Res<LevelData>,
+    level_session: Option<Res<LevelSession>>,
+    mut level_failed_events: EventWriter<LevelFailedEvent>,
 ) {
     for (interaction, mut color, button_type) in &mut interaction_query {
         match *interaction {
@@ -805,6 +795,8 @@ fn level_selection_interactions(
                 }
                 LevelSelectionButton::LevelButton(level_id) => {
                     if level_data.is_level_unlocked(*level_id) {
+                        quit_current_level(&level_data, level_session.as_deref(), &mut level_failed_events);
+
                         // Start the selected level
                         level_start_events.write(LevelStartEvent { level_id: *level_id });
 
@@ -847,11 +839,13 @@ fn setup_pause_menu_on_pause(
     asset_server: Res<AssetServer>,
     level_data: Res<LevelData>,
     game_assets: Res<crate::GameAssets>,
+    bindings: Res<ActionBindings>,
 ) {
     match pause_menu_state.get() {
         PauseMenuState::PauseMenu => setup_pause_menu(commands),
         PauseMenuState::PowerupHelp => setup_powerup_help_menu(commands, asset_server),
         PauseMenuState::LevelSelection => setup_level_selection_menu(commands, level_data, game_assets),
+        PauseMenuState::Controls => setup_controls_menu(commands, bindings),
     }
 }
 
@@ -862,6 +856,7 @@ fn switch_pause_menu_content(
     asset_server: Res<AssetServer>,
     level_data: Res<LevelData>,
     game_assets: Res<crate::GameAssets>,
+    bindings: Res<ActionBindings>,
     pause_entities: Query<Entity, With<PauseMenuEntity>>,
     mut local_previous_state: Local<Option<PauseMenuState>>,
 ) {
@@ -879,6 +874,7 @@ fn switch_pause_menu_content(
                 PauseMenuState::PauseMenu => setup_pause_menu(commands),
                 PauseMenuState::PowerupHelp => setup_powerup_help_menu(commands, asset_server),
                 PauseMenuState::LevelSelection => setup_level_selection_menu(commands, level_data, game_assets),
+                PauseMenuState::Controls => setup_controls_menu(commands, bindings),
             }
         }
     }
@@ -886,6 +882,61 @@ fn switch_pause_menu_content(
     *local_previous_state = Some(current_state);
 }
 
+/// One row of the powerup help table: icon, name and description
+fn spawn_powerup_help_row(parent: &mut ChildSpawnerCommands, icon: Handle<Image>, name: &str, description: &str) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                min_height: Val::Vh(10.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Vw(3.0),
+                padding: UiRect::all(Val::Vh(1.5)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+            BorderRadius::all(Val::Px(5.0)),
+        ))
+        .with_children(|parent| {
+            // Powerup icon
+            parent.spawn((
+                ImageNode::new(icon),
+                Node {
+                    width: Val::Vw(8.0),
+                    height: Val::Vw(8.0),
+                    max_width: Val::Px(60.0),
+                    max_height: Val::Px(60.0),
+                    ..default()
+                },
+            ));
+
+            // Description text
+            parent
+                .spawn((Node {
+                    flex_direction: FlexDirection::Column,
+                    flex_grow: 1.0,
+                    row_gap: Val::Vh(0.5),
+                    ..default()
+                },))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(name),
+                        TextFont { font_size: 18.0, ..default() },
+                        TextColor(Color::srgb(0.9, 0.9, 0.5)),
+                        DynamicFontSize { base_size: 18.0 },
+                    ));
+
+                    parent.spawn((
+                        Text::new(description),
+                        TextFont { font_size: 14.0, ..default() },
+                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                        DynamicFontSize { base_size: 14.0 },
+                    ));
+                });
+        });
+}
+
 /// Handle powerup help menu button interactions
 fn powerup_help_interactions(
     mut interaction_query: Query<(&Interaction, &mut BackgroundColor, &PowerupHelpButton), (Changed<Interaction>, With<Button>)>,
@@ -908,6 +959,246 @@ fn powerup_help_interactions(
     }
 }
 
+/// Setup controls menu UI, listing every rebindable action
+fn setup_controls_menu(mut commands: Commands, bindings: Res<ActionBindings>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                padding: UiRect::all(Val::VMin(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            PauseMenuEntity,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Vw(85.0),
+                        max_width: Val::Px(600.0),
+                        min_width: Val::Px(300.0),
+                        max_height: Val::Vh(90.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::VMin(2.0)),
+                        row_gap: Val::VMin(1.5),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                    BorderRadius::all(Val::VMin(1.5)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Controls"),
+                        TextFont { font_size: 24.0, ..default() },
+                        TextColor(Color::WHITE),
+                        DynamicFontSize { base_size: 24.0 },
+                    ));
+
+                    parent.spawn((
+                        Text::new("Click an action, then press its new key. Left click cancels."),
+                        TextFont { font_size: 14.0, ..default() },
+                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                        DynamicFontSize { base_size: 14.0 },
+                    ));
+
+                    for action in BindableAction::ALL {
+                        parent
+                            .spawn((
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    flex_direction: FlexDirection::Row,
+                                    align_items: AlignItems::Center,
+                                    justify_content: JustifyContent::SpaceBetween,
+                                    column_gap: Val::Vw(2.0),
+                                    ..default()
+                                },
+                                PauseMenuEntity,
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(action.label()),
+                                    TextFont { font_size: 16.0, ..default() },
+                                    TextColor(Color::srgb(0.9, 0.9, 0.5)),
+                                    DynamicFontSize { base_size: 16.0 },
+                                ));
+
+                                parent
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            width: Val::Percent(50.0),
+                                            height: Val::Vh(6.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+                                        BorderRadius::all(Val::Px(5.0)),
+                                        ControlsButton::Rebind(action),
+                                        PauseMenuEntity,
+                                    ))
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            Text::new(binding_label(bindings.inputs(action))),
+                                            TextFont { font_size: 16.0, ..default() },
+                                            TextColor(Color::WHITE),
+                                            DynamicFontSize { base_size: 16.0 },
+                                            BindingText(action),
+                                        ));
+                                    });
+                            });
+                    }
+
+                    parent
+                        .spawn((Node {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Vw(3.0),
+                            margin: UiRect::top(Val::Vh(1.5)),
+                            ..default()
+                        },))
+                        .with_children(|parent| {
+                            for (button_type, label, color) in [
+                                (ControlsButton::ResetDefaults, "Reset Defaults", Color::srgb(0.6, 0.3, 0.3)),
+                                (ControlsButton::Back, "Back", Color::srgb(0.3, 0.3, 0.3)),
+                            ] {
+                                parent
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            width: Val::Vw(30.0),
+                                            max_width: Val::Px(200.0),
+                                            height: Val::Vh(7.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        BackgroundColor(color),
+                                        BorderRadius::all(Val::Px(5.0)),
+                                        button_type,
+                                        PauseMenuEntity,
+                                    ))
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            Text::new(label),
+                                            TextFont { font_size: 18.0, ..default() },
+                                            TextColor(Color::WHITE),
+                                            DynamicFontSize { base_size: 18.0 },
+                                        ));
+                                    });
+                            }
+                        });
+                });
+        });
+}
+
+/// Inputs bound to an action, as shown on the controls menu
+fn binding_label(inputs: &[Input]) -> String {
+    if inputs.is_empty() {
+        return "Unbound".to_string();
+    }
+    inputs.iter().map(ToString::to_string).collect::<Vec<_>>().join(" / ")
+}
+
+/// Bind the next key, or mouse button other than left, to the action waiting for input; a left click cancels
+fn capture_rebind_input(
+    mut commands: Commands,
+    mut pending_rebind: ResMut<PendingRebind>,
+    mut bindings: ResMut<ActionBindings>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+) {
+    let Some(action) = pending_rebind.0 else {
+        return;
+    };
+
+    // Left click stays free for pressing the menu's buttons
+    if mouse_input.just_pressed(MouseButton::Left) {
+        pending_rebind.0 = None;
+        return;
+    }
+
+    let input: Option<Input> = keyboard_input
+        .get_just_pressed()
+        .next()
+        .map(|&key| key.into())
+        .or_else(|| mouse_input.get_just_pressed().next().map(|&button| button.into()));
+    let Some(input) = input else {
+        return;
+    };
+
+    *bindings.inputs_mut(action) = vec![input];
+    pending_rebind.0 = None;
+    crate::actions::save_action_bindings(&bindings);
+    // Only the paused context is active; the others read the new bindings when they are next activated
+    commands.trigger(RebuildBindings);
+    info!("Bound {} to {}", action.label(), input);
+}
+
+/// Handle controls menu button interactions
+fn controls_interactions(
+    mut commands: Commands,
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor, &ControlsButton), (Changed<Interaction>, With<Button>)>,
+    mut next_pause_menu_state: ResMut<NextState<PauseMenuState>>,
+    mut pending_rebind: ResMut<PendingRebind>,
+    mut bindings: ResMut<ActionBindings>,
+) {
+    for (interaction, mut color, button_type) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => match button_type {
+                ControlsButton::Back => {
+                    pending_rebind.0 = None;
+                    next_pause_menu_state.set(PauseMenuState::PauseMenu);
+                }
+                ControlsButton::ResetDefaults => {
+                    pending_rebind.0 = None;
+                    *bindings = ActionBindings::default();
+                    crate::actions::save_action_bindings(&bindings);
+                    commands.trigger(RebuildBindings);
+                    info!("Input bindings reset to defaults");
+                }
+                ControlsButton::Rebind(action) => {
+                    pending_rebind.0 = Some(*action);
+                }
+            },
+            Interaction::Hovered => match button_type {
+                ControlsButton::Back | ControlsButton::Rebind(_) => *color = BackgroundColor(Color::srgb(0.5, 0.5, 0.5)),
+                ControlsButton::ResetDefaults => *color = BackgroundColor(Color::srgb(0.8, 0.4, 0.4)),
+            },
+            Interaction::None => match button_type {
+                ControlsButton::Back | ControlsButton::Rebind(_) => *color = BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+                ControlsButton::ResetDefaults => *color = BackgroundColor(Color::srgb(0.6, 0.3, 0.3)),
+            },
+        }
+    }
+}
+
+/// Show each action's bindings, or a prompt on the action waiting for input
+fn update_binding_texts(pending_rebind: Res<PendingRebind>, bindings: Res<ActionBindings>, mut text_query: Query<(&mut Text, &BindingText)>) {
+    if !pending_rebind.is_changed() && !bindings.is_changed() {
+        return;
+    }
+
+    for (mut text, binding_text) in &mut text_query {
+        text.0 = if pending_rebind.0 == Some(binding_text.0) {
+            "Press a key...".to_string()
+        } else {
+            binding_label(bindings.inputs(binding_text.0))
+        };
+    }
+}
+
+/// Drop an unfinished rebind when the game is resumed
+fn cancel_pending_rebind(mut pending_rebind: ResMut<PendingRebind>) {
+    pending_rebind.0 = None;
+}
+
 /// Pause all active sound entities when game is paused
 fn pause_sounds(sound_query: Query<&AudioSink, With<crate::SoundEntity>>) {
     for sink in &sound_query {
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+
+/// Share of the play area height covered by the top UI panel
+const TOP_UI_HEIGHT_RATIO: f32 = 0.12;
+/// Share of the play area height covered by the bottom UI panel
+const BOTTOM_UI_HEIGHT_RATIO: f32 = 0.08;
+
+/// Plugin that keeps `PlayArea` in sync with the primary window
+pub struct PlayAreaPlugin;
+
+impl Plugin for PlayAreaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayArea>().add_systems(PreUpdate, sync_play_area_with_window);
+    }
+}
+
+/// Size of the visible lawn in world units, so gameplay doesn't depend on a window existing
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct PlayArea {
+    pub size: Vec2,
+}
+
+impl Default for PlayArea {
+    fn default() -> Self {
+        Self {
+            size: Vec2::new(1280.0, 720.0),
+        }
+    }
+}
+
+impl PlayArea {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            size: Vec2::new(width, height),
+        }
+    }
+
+    /// Grass area between the top and bottom UI panels, shrunk by `margin` on every side
+    pub fn grass_bounds(&self, margin: f32) -> Rect {
+        let top_ui_height = self.size.y * TOP_UI_HEIGHT_RATIO;
+        let bottom_ui_height = self.size.y * BOTTOM_UI_HEIGHT_RATIO;
+
+        Rect::new(
+            -self.size.x / 2.0 + margin,
+            -self.size.y / 2.0 + bottom_ui_height + margin,
+            self.size.x / 2.0 - margin,
+            self.size.y / 2.0 - top_ui_height - margin,
+        )
+    }
+}
+
+/// Track window resizes
+fn sync_play_area_with_window(windows: Query<&Window>, mut play_area: ResMut<PlayArea>) {
+    if let Ok(window) = windows.single() {
+        let size = window.size();
+        if play_area.size != size {
+            play_area.size = size;
+        }
+    }
+}
@@ -1,8 +1,13 @@
 use bevy::prelude::*;
+use bevy_enhanced_input::prelude::Started;
 
-use crate::GameState;
-use crate::levels::{LevelCompleteEvent, LevelData, LevelStartEvent};
+use crate::actions::Pause;
+use crate::enemies::SlashTrails;
+use crate::input::{GamepadReticle, PlayerInput};
+use crate::levels::{FailureReason, LevelCompleteEvent, LevelData, LevelFailedEvent, LevelStartEvent};
 use crate::pause_menu::{PauseMenuState, PauseState};
+use crate::powerups::{PoisonZone, PowerupSlot, PowerupSlotCount, PowerupType};
+use crate::{GameAssets, GameState};
 
 // Constants for UI and gameplay
 const TOP_UI_HEIGHT: f32 = 12.0; // Viewport height percentage
@@ -12,6 +17,7 @@ const GRASS_BACKGROUND_COLOR: Color = Color::srgb(0.2, 0.6, 0.2);
 const UI_BACKGROUND_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.8);
 const COMBO_TIMER_WIDTH: f32 = 80.0;
 const COMBO_TIMER_HEIGHT: f32 = 6.0;
+const RETICLE_RADIUS: f32 = 16.0;
 
 /// Plugin for handling the main gameplay
 pub struct PlayingPlugin;
@@ -20,28 +26,44 @@ impl Plugin for PlayingPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             OnEnter(GameState::Playing),
-            (setup_game_resources, setup_game_camera, setup_game_ui, setup_level_complete_overlay).chain(),
+            (
+                setup_game_resources,
+                setup_game_camera,
+                setup_game_ui,
+                setup_level_complete_overlay,
+                setup_level_failed_overlay,
+            )
+                .chain(),
         )
         .add_systems(
             Update,
             (
-                handle_game_input,
                 handle_button_interactions,
+                apply_slash_mode_toggle,
                 update_ui,
                 update_button_text,
                 update_combo_timer,
                 update_slash_effects,
-                update_delayed_slash_effects,
+                draw_slash_trails,
+                draw_gamepad_reticle,
+                draw_poison_zones,
+                update_delayed_slash_effects.after(crate::enemies::RebuildDandelionGrid),
                 handle_level_completion_events,
-                handle_level_start_events,
+                handle_level_failed_events,
                 update_dynamic_font_sizes,
             )
                 .run_if(in_state(PauseState::Playing))
                 .run_if(in_state(GameState::Playing)),
         )
-        .add_systems(Update, handle_level_completion_interactions.run_if(in_state(GameState::Playing)))
+        .add_systems(
+            Update,
+            (handle_level_completion_interactions, handle_level_failed_interactions).run_if(in_state(GameState::Playing)),
+        )
+        // Level (re)starts are applied before Update so every level begins from the same state
+        .add_systems(PreUpdate, handle_level_start_events.run_if(in_state(GameState::Playing)))
         .add_systems(OnEnter(GameState::Playing), play_level1_music.after(setup_game_resources))
-        .add_systems(OnExit(GameState::Playing), cleanup_game);
+        .add_systems(OnExit(GameState::Playing), cleanup_game)
+        .add_observer(pause_game);
     }
 }
 
@@ -57,31 +79,32 @@ pub struct GameData {
     pub combo_timer: Timer,
     pub dandelion_count: u32,
     pub slash_mode: bool,
-    pub slash_offset: f32,
+    /// Longest slash trail a swipe can draw, in world units
+    pub slash_length: f32,
     pub music_enabled: bool,
 }
 
 impl GameData {
-    const DANDELION_POINTS: u32 = 10;
     const INITIAL_COMBO_TIME: f32 = 3.0;
     const MAX_COMBO_TIME: f32 = 6.0;
-    const DEFAULT_SLASH_OFFSET: f32 = 30.0; // Distance from click point to slash endpoints (about 3 pointers)
+    const DEFAULT_SLASH_LENGTH: f32 = 240.0; // About seven tiny dandelions across
 
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             score: 0,
             combo: 0,
             combo_timer: Timer::from_seconds(Self::INITIAL_COMBO_TIME, TimerMode::Once),
             dandelion_count: 0,
             slash_mode: true,
-            slash_offset: Self::DEFAULT_SLASH_OFFSET,
+            slash_length: Self::DEFAULT_SLASH_LENGTH,
             music_enabled: true,
         }
     }
 
-    pub fn add_dandelion_kill(&mut self) {
+    /// Score a kill of `species` and extend the combo
+    pub fn add_kill(&mut self, species: crate::enemies::Species) {
         self.combo = self.combo.saturating_add(1);
-        self.score = self.score.saturating_add(Self::DANDELION_POINTS.saturating_mul(self.combo));
+        self.score = self.score.saturating_add(species.points().saturating_mul(self.combo));
 
         // Calculate new timer duration based on combo level (logarithmic growth)
         let combo_factor = (self.combo as f32).ln() + 1.0;
@@ -146,13 +169,28 @@ struct LevelCompleteStars;
 #[derive(Component)]
 struct LevelCompleteContinueButton;
 
+/// Component for level failed overlay
+#[derive(Component)]
+struct LevelFailedOverlay;
+
+/// Component for level failed text elements
+#[derive(Component)]
+struct LevelFailedText;
+
+/// Level failed overlay button types
+#[derive(Component)]
+enum LevelFailedButton {
+    Retry,
+    LevelSelect,
+}
+
 /// Button for pausing the game
 #[derive(Component)]
 struct PauseButton;
 
 /// Button for switching attack mode
 #[derive(Component)]
-struct AttackModeButton;
+pub struct AttackModeButton;
 
 /// Button for toggling music
 #[derive(Component)]
@@ -168,8 +206,7 @@ pub struct SlashEffect {
 #[derive(Component)]
 pub struct DelayedSlashEffect {
     delay_timer: Timer,
-    slash_start: Vec2,
-    slash_end: Vec2,
+    trail: Vec<Vec2>,
 }
 
 /// Marker component for dynamic font scaling
@@ -179,7 +216,7 @@ struct DynamicFontSize {
 }
 
 /// Initialize game resources
-fn setup_game_resources(mut commands: Commands) {
+pub(crate) fn setup_game_resources(mut commands: Commands) {
     commands.insert_resource(GameData::new());
 
     // Initialize level session and start it fresh
@@ -218,7 +255,7 @@ fn setup_game_camera(mut commands: Commands) {
 }
 
 /// Setup the game UI layout
-fn setup_game_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn setup_game_ui(mut commands: Commands, asset_server: Res<AssetServer>, assets: Res<GameAssets>, level_data: Res<LevelData>) {
     commands
         .spawn((
             Node {
@@ -327,6 +364,15 @@ fn setup_game_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
                         DynamicFontSize { base_size: 20.0 },
                     ));
 
+                    // Wind display
+                    parent.spawn((
+                        Text::new("Wind: calm"),
+                        TextFont { font_size: 18.0, ..default() },
+                        TextColor(Color::srgb(0.7, 0.85, 1.0)),
+                        crate::wind::WindText,
+                        DynamicFontSize { base_size: 18.0 },
+                    ));
+
                     // Attack mode display
                     parent.spawn((
                         Text::new("Click"),
@@ -392,6 +438,57 @@ fn setup_game_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
                             ));
                         });
 
+                    // Middle: Powerup inventory slots, tap one and then the lawn to deploy it
+                    parent
+                        .spawn((Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(10.0),
+                            ..default()
+                        },))
+                        .with_children(|parent| {
+                            for powerup_type in PowerupType::all() {
+                                let charges = level_data.powerup_inventory.charges(powerup_type);
+                                parent
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            width: Val::Px(64.0),
+                                            height: Val::Px(45.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            column_gap: Val::Px(4.0),
+                                            ..default()
+                                        },
+                                        BackgroundColor(if charges == 0 {
+                                            Color::srgb(0.25, 0.25, 0.25)
+                                        } else {
+                                            Color::srgb(0.3, 0.4, 0.6)
+                                        }),
+                                        BorderRadius::all(Val::Px(8.0)),
+                                        PowerupSlot(powerup_type),
+                                        GameEntity,
+                                    ))
+                                    .with_children(|parent| {
+                                        parent.spawn((
+                                            ImageNode::new(powerup_type.image(&assets)),
+                                            Node {
+                                                width: Val::Px(30.0),
+                                                height: Val::Px(30.0),
+                                                ..default()
+                                            },
+                                        ));
+                                        parent.spawn((
+                                            Text::new(format!("x{}", charges)),
+                                            TextFont { font_size: 16.0, ..default() },
+                                            TextColor(Color::WHITE),
+                                            DynamicFontSize { base_size: 16.0 },
+                                            PowerupSlotCount(powerup_type),
+                                        ));
+                                    });
+                            }
+                        });
+
                     // Right side: Mobile control buttons
                     parent
                         .spawn((Node {
@@ -480,28 +577,19 @@ fn setup_game_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
         });
 }
 
-/// Handle input during gameplay
-fn handle_game_input(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    pause_state: Res<State<PauseState>>,
+/// Open the pause menu when the Pause action is pressed during gameplay
+fn pause_game(
+    _trigger: Trigger<Started<Pause>>,
     mut next_pause_state: ResMut<NextState<PauseState>>,
     mut next_pause_menu_state: ResMut<NextState<PauseMenuState>>,
-    mut game_data: ResMut<GameData>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::KeyQ) {
-        match pause_state.get() {
-            PauseState::Playing => {
-                next_pause_state.set(PauseState::Paused);
-                next_pause_menu_state.set(PauseMenuState::PauseMenu);
-            }
-            PauseState::Paused => {
-                next_pause_state.set(PauseState::Playing);
-            }
-        }
-    }
+    next_pause_state.set(PauseState::Paused);
+    next_pause_menu_state.set(PauseMenuState::PauseMenu);
+}
 
-    // Toggle slash mode with Tab key
-    if keyboard_input.just_pressed(KeyCode::Tab) {
+/// Switch attack mode when requested with the ToggleMode action or the attack mode button
+fn apply_slash_mode_toggle(player_input: Res<PlayerInput>, mut game_data: ResMut<GameData>) {
+    if player_input.toggle_slash_mode {
         game_data.toggle_slash_mode();
         let mode_text = if game_data.slash_mode { "slash" } else { "click" };
         info!("Switched to {} mode", mode_text);
@@ -541,11 +629,6 @@ fn handle_button_interactions(
                     }
                 }
 
-                if attack_mode_button.is_some() {
-                    game_data.toggle_slash_mode();
-                    info!("Switched to {} mode", if game_data.slash_mode { "slash" } else { "click" });
-                }
-
                 if music_button.is_some() {
                     game_data.toggle_music();
                     if let Ok(sink) = music_query.single() {
@@ -580,20 +663,20 @@ fn handle_button_interactions(
     }
 }
 
-/// Calculate curb appeal based on dandelion count and types
+/// Calculate curb appeal based on weed count, sizes and species
 fn calculate_curb_appeal(dandelion_query: &Query<&crate::enemies::Dandelion>) -> i32 {
     let mut total_impact = 0.0;
 
-    // Count dandelions by size and calculate their curb appeal impact
+    // Count weeds by size and species and calculate their curb appeal impact
     for dandelion in dandelion_query.iter() {
-        let impact = match dandelion.size {
+        let size_impact = match dandelion.size {
             crate::enemies::DandelionSize::Tiny => 1.0,
             crate::enemies::DandelionSize::Small => 1.5,
             crate::enemies::DandelionSize::Medium => 2.5,
             crate::enemies::DandelionSize::Large => 4.0,
             crate::enemies::DandelionSize::Huge => 6.0,
         };
-        total_impact += impact;
+        total_impact += size_impact * dandelion.species.curb_appeal_weight();
     }
 
     // More forgiving formula that keeps appeal higher for longer
@@ -883,7 +966,7 @@ fn update_button_text(
 }
 
 /// Update combo timer and reset combo when it expires
-fn update_combo_timer(mut game_data: ResMut<GameData>, time: Res<Time>) {
+pub fn update_combo_timer(mut game_data: ResMut<GameData>, time: Res<Time>) {
     if game_data.combo > 0 {
         game_data.combo_timer.tick(time.delta());
 
@@ -916,6 +999,7 @@ fn update_delayed_slash_effects(
     mut commands: Commands,
     mut delayed_query: Query<(Entity, &mut DelayedSlashEffect)>,
     mut dandelion_query: Query<(Entity, &mut crate::enemies::Dandelion, &Transform)>,
+    grid: Res<crate::enemies::DandelionGrid>,
     time: Res<Time>,
     game_assets: Res<crate::GameAssets>,
     mut game_data: ResMut<GameData>,
@@ -925,17 +1009,11 @@ fn update_delayed_slash_effects(
 
         if delayed_effect.delay_timer.just_finished() {
             // Spawn the actual slash effect
-            spawn_slash_effect(&mut commands, delayed_effect.slash_start, delayed_effect.slash_end);
+            spawn_slash_effect(&mut commands, &delayed_effect.trail);
 
             // Process delayed slash damage and only play sound if enemies are hit
-            let _hit_count = crate::enemies::process_delayed_slash_damage(
-                &mut commands,
-                &mut game_data,
-                &game_assets,
-                &mut dandelion_query,
-                delayed_effect.slash_start,
-                delayed_effect.slash_end,
-            );
+            let _hit_count =
+                crate::enemies::process_delayed_slash_damage(&mut commands, &mut game_data, &game_assets, &mut dandelion_query, &grid, &delayed_effect.trail);
 
             // Remove the delayed effect entity
             if let Ok(mut ec) = commands.get_entity(entity) {
@@ -945,40 +1023,80 @@ fn update_delayed_slash_effects(
     }
 }
 
-/// Spawn a visual slash effect
-pub fn spawn_slash_effect(commands: &mut Commands, start_pos: Vec2, end_pos: Vec2) {
-    let direction = end_pos - start_pos;
-    let length = direction.length();
-    let angle = direction.y.atan2(direction.x);
-    let center = (start_pos + end_pos) / 2.0;
-
-    commands.spawn((
-        Sprite {
-            color: Color::srgba(1.0, 1.0, 0.0, 0.8), // Bright yellow slash
-            ..default()
-        },
-        Transform::from_translation(Vec3::new(center.x, center.y, 20.0))
-            .with_rotation(Quat::from_rotation_z(angle))
-            .with_scale(Vec3::new(length, 4.0, 1.0)), // 4 pixel wide line
-        SlashEffect {
-            timer: Timer::from_seconds(0.2, TimerMode::Once), // 200ms duration
-        },
-        GameEntity, // Add GameEntity component for proper cleanup
-    ));
+/// Spawn a visual slash effect along a swiped trail, one line per segment
+pub fn spawn_slash_effect(commands: &mut Commands, trail: &[Vec2]) {
+    // A tap leaves a single point, drawn as a dot
+    let segments = trail.windows(2).map(|segment| (segment[0], segment[1]));
+    let tap = (trail.len() == 1).then(|| (trail[0], trail[0]));
+
+    for (start_pos, end_pos) in segments.chain(tap) {
+        let direction = end_pos - start_pos;
+        let length = direction.length().max(4.0);
+        let angle = direction.y.atan2(direction.x);
+        let center = (start_pos + end_pos) / 2.0;
+
+        commands.spawn((
+            Sprite {
+                color: Color::srgba(1.0, 1.0, 0.0, 0.8), // Bright yellow slash
+                ..default()
+            },
+            Transform::from_translation(Vec3::new(center.x, center.y, 20.0))
+                .with_rotation(Quat::from_rotation_z(angle))
+                .with_scale(Vec3::new(length, 4.0, 1.0)), // 4 pixel wide line
+            SlashEffect {
+                timer: Timer::from_seconds(0.2, TimerMode::Once), // 200ms duration
+            },
+            GameEntity, // Add GameEntity component for proper cleanup
+        ));
+    }
 }
 
 /// Spawn a delayed slash effect for double slash
-pub fn spawn_delayed_slash_effect(commands: &mut Commands, start_pos: Vec2, end_pos: Vec2, delay: f32) {
+pub fn spawn_delayed_slash_effect(commands: &mut Commands, trail: Vec<Vec2>, delay: f32) {
     commands.spawn((
         DelayedSlashEffect {
             delay_timer: Timer::from_seconds(delay, TimerMode::Once),
-            slash_start: start_pos,
-            slash_end: end_pos,
+            trail,
         },
         GameEntity,
     ));
 }
 
+/// Draw the slash trails while they are being swiped
+fn draw_slash_trails(slash_trails: Res<SlashTrails>, mut gizmos: Gizmos) {
+    for trail in slash_trails.values() {
+        gizmos.linestrip_2d(trail.points().iter().copied(), Color::srgba(1.0, 1.0, 0.0, 0.5));
+    }
+}
+
+/// Outline the area each weed killer spray poisons, fading with its sprite
+fn draw_poison_zones(zones: Query<(&Transform, &PoisonZone, &Sprite)>, mut gizmos: Gizmos) {
+    for (transform, zone, sprite) in &zones {
+        let color = Color::srgb(0.5, 0.9, 0.2).with_alpha(sprite.color.alpha() * 0.8);
+        gizmos.circle_2d(transform.translation.truncate(), zone.radius(), color);
+    }
+}
+
+/// Draw the gamepad reticle while the gamepad is in use
+fn draw_gamepad_reticle(reticle: Res<GamepadReticle>, mut gizmos: Gizmos) {
+    if !reticle.visible {
+        return;
+    }
+
+    let color = Color::srgba(1.0, 1.0, 1.0, 0.8);
+    gizmos.circle_2d(reticle.position, RETICLE_RADIUS, color);
+    gizmos.line_2d(
+        reticle.position - Vec2::X * RETICLE_RADIUS * 0.5,
+        reticle.position + Vec2::X * RETICLE_RADIUS * 0.5,
+        color,
+    );
+    gizmos.line_2d(
+        reticle.position - Vec2::Y * RETICLE_RADIUS * 0.5,
+        reticle.position + Vec2::Y * RETICLE_RADIUS * 0.5,
+        color,
+    );
+}
+
 /// Update dynamic font sizes based on window dimensions
 fn update_dynamic_font_sizes(windows: Query<&Window>, mut text_query: Query<(&mut TextFont, &DynamicFontSize)>) {
     for (mut text_font, dynamic_size) in &mut text_query {
@@ -1043,11 +1161,11 @@ fn handle_level_start_events(
     powerup_entities: Query<Entity, With<crate::powerups::PowerupEntity>>,
     rabbit_entities: Query<Entity, With<crate::powerups::Rabbit>>,
     fire_entities: Query<Entity, With<crate::powerups::FireIgnition>>,
-    mut level_complete_overlay_query: Query<&mut Visibility, With<LevelCompleteOverlay>>,
+    mut level_overlay_query: Query<&mut Visibility, Or<(With<LevelCompleteOverlay>, With<LevelFailedOverlay>)>>,
 ) {
     for event in level_start_events.read() {
-        // Hide level complete overlay if visible
-        for mut visibility in &mut level_complete_overlay_query {
+        // Hide level complete and failed overlays if visible
+        for mut visibility in &mut level_overlay_query {
             *visibility = Visibility::Hidden;
         }
 
@@ -1079,8 +1197,7 @@ fn handle_level_start_events(
 
         // Reset game data for the selected level
         game_data.score = 0;
-        game_data.combo = 0;
-        game_data.combo_timer.reset();
+        game_data.reset_combo();
         game_data.dandelion_count = 0;
 
         // Set the current level to the selected level
@@ -1148,6 +1265,74 @@ fn handle_level_completion_events(
     }
 }
 
+/// Show the failure overlay when the level runs out of time
+fn handle_level_failed_events(
+    mut level_failed_events: EventReader<LevelFailedEvent>,
+    mut level_failed_overlay_query: Query<&mut Visibility, With<LevelFailedOverlay>>,
+    mut level_failed_text_query: Query<&mut Text, With<LevelFailedText>>,
+    game_data: Res<GameData>,
+    level_data: Res<LevelData>,
+) {
+    for event in level_failed_events.read() {
+        // Quitting leaves the playing state, so only timeouts need an overlay
+        if !matches!(event.reason, FailureReason::TimeOut) {
+            continue;
+        }
+
+        for mut visibility in &mut level_failed_overlay_query {
+            *visibility = Visibility::Visible;
+        }
+
+        let target_points = level_data.get_level(event.level_id).map(|level| level.target_points).unwrap_or(0);
+        for mut text in &mut level_failed_text_query {
+            text.0 = format!("Time's Up!\n\nLevel {}\nScore: {} / {}", event.level_id, game_data.score, target_points);
+        }
+
+        info!("Level failed overlay shown for level {}", event.level_id);
+    }
+}
+
+/// Handle interactions with the level failed overlay
+fn handle_level_failed_interactions(
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor, &LevelFailedButton), (Changed<Interaction>, With<Button>)>,
+    mut level_failed_overlay_query: Query<&mut Visibility, With<LevelFailedOverlay>>,
+    level_data: Res<LevelData>,
+    mut level_start_events: EventWriter<LevelStartEvent>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+    mut next_pause_menu_state: ResMut<NextState<PauseMenuState>>,
+) {
+    for (interaction, mut color, button_type) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => match button_type {
+                LevelFailedButton::Retry => {
+                    for mut visibility in &mut level_failed_overlay_query {
+                        *visibility = Visibility::Hidden;
+                    }
+
+                    // Restarting the same level clears the board and resets the session
+                    level_start_events.write(LevelStartEvent {
+                        level_id: level_data.current_level,
+                    });
+                    info!("Retrying level {}", level_data.current_level);
+                }
+                LevelFailedButton::LevelSelect => {
+                    // Overlay stays up underneath so backing out of the menu returns here
+                    next_pause_menu_state.set(PauseMenuState::LevelSelection);
+                    next_pause_state.set(PauseState::Paused);
+                }
+            },
+            Interaction::Hovered => match button_type {
+                LevelFailedButton::Retry => *color = BackgroundColor(Color::srgb(0.3, 0.8, 0.3)),
+                LevelFailedButton::LevelSelect => *color = BackgroundColor(Color::srgb(0.5, 0.5, 0.7)),
+            },
+            Interaction::None => match button_type {
+                LevelFailedButton::Retry => *color = BackgroundColor(Color::srgb(0.2, 0.7, 0.2)),
+                LevelFailedButton::LevelSelect => *color = BackgroundColor(Color::srgb(0.4, 0.4, 0.6)),
+            },
+        }
+    }
+}
+
 /// Handle interactions with the level completion overlay
 fn handle_level_completion_interactions(
     mut commands: Commands,
@@ -1331,3 +1516,90 @@ fn setup_level_complete_overlay(mut commands: Commands, _asset_server: Res<Asset
                 });
         });
 }
+
+/// Setup the level failed overlay UI
+fn setup_level_failed_overlay(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            Visibility::Hidden,
+            LevelFailedOverlay,
+            GameEntity,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Vw(85.0),
+                        max_width: Val::Px(600.0),
+                        height: Val::Vh(70.0),
+                        max_height: Val::Px(450.0),
+                        min_height: Val::Vh(50.0),
+                        padding: UiRect::all(Val::VMin(3.0)),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.15, 0.05, 0.05, 0.95)),
+                    BorderRadius::all(Val::VMin(2.0)),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Time's Up!"),
+                        TextFont { font_size: 36.0, ..default() },
+                        TextColor(Color::WHITE),
+                        LevelFailedText,
+                        DynamicFontSize { base_size: 36.0 },
+                        Node {
+                            margin: UiRect::bottom(Val::Vh(3.0)),
+                            ..default()
+                        },
+                    ));
+
+                    for (button_type, label, color) in [
+                        (LevelFailedButton::Retry, "Retry", Color::srgb(0.2, 0.7, 0.2)),
+                        (LevelFailedButton::LevelSelect, "Level Select", Color::srgb(0.4, 0.4, 0.6)),
+                    ] {
+                        parent
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Vw(25.0),
+                                    max_width: Val::Px(200.0),
+                                    min_width: Val::Px(150.0),
+                                    height: Val::Vh(8.0),
+                                    max_height: Val::Px(60.0),
+                                    min_height: Val::Px(45.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    margin: UiRect::top(Val::Vh(2.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(color),
+                                BorderRadius::all(Val::VMin(1.5)),
+                                button_type,
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(label),
+                                    TextFont { font_size: 20.0, ..default() },
+                                    TextColor(Color::WHITE),
+                                    DynamicFontSize { base_size: 20.0 },
+                                ));
+                            });
+                    }
+                });
+        });
+}
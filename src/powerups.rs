@@ -1,25 +1,52 @@
 use bevy::prelude::*;
+#[cfg(feature = "debug_keys")]
+use bevy_enhanced_input::prelude::Started;
 use rand::Rng;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-use crate::enemies::{Dandelion, DandelionAreaTracker};
+#[cfg(feature = "debug_keys")]
+use crate::actions::{SpawnDandelionRing, SpawnFire, SpawnGeese, SpawnLawnMower, SpawnRabbits, SpawnWeedKiller};
+use crate::combos::{CollectPowerupAreas, PowerupAreas, PowerupTraces};
+use crate::enemies::{
+    Dandelion, DandelionAreaTracker, DandelionDeathEvent, DandelionGrid, DandelionSize, MoveSeedOrbs, RebuildDandelionGrid, SeedOrb,
+    distance_point_to_line_segment,
+};
+use crate::input::PlayerInput;
+use crate::levels::{LevelData, LevelStartEvent, level_not_failed};
 use crate::pause_menu::PauseState;
+use crate::play_area::PlayArea;
 use crate::playing::GameData;
+use crate::rng::GameRng;
 use crate::{GameAssets, GameState};
 
 // Constants for powerup behavior
 const POWERUP_SPAWN_INTERVAL: f32 = 10.0;
 const POWERUP_CLICK_RADIUS: f32 = 30.0;
+const RABBIT_COUNT: u32 = 3;
 const RABBIT_LIFETIME: f32 = 3.0;
 const RABBIT_SPEED: f32 = 120.0;
+const RABBIT_LITTER_AFTER: u32 = 2; // Dandelions a rabbit eats before it multiplies
 const RABBIT_EAT_DISTANCE: f32 = 25.0;
 const RABBIT_SCALE: f32 = 0.2; // Scale 175px sprite to 35px
 const FLAMETHROWER_SCALE: f32 = 0.2; // Scale 175px sprite to 35px
 const FIRE_RADIUS: f32 = 100.0;
 const FIRE_LIFETIME: f32 = 3.0;
+const WEED_KILLER_SCALE: f32 = 0.2; // Scale 175px sprite to 35px
+const POISON_RADIUS: f32 = 90.0;
+const POISON_LIFETIME: f32 = 6.0;
+const POISON_DAMAGE_INTERVAL: f32 = 0.5;
+const LAWN_MOWER_SCALE: f32 = 0.3; // Scale 175px sprite to about 50px
+const LAWN_MOWER_SPEED: f32 = 400.0;
+const LAWN_MOWER_HALF_WIDTH: f32 = 25.0; // Half the width of the cut lane
+const LAWN_MOWER_DAMAGE: u32 = 3;
+const GOOSE_COUNT: u32 = 2;
+const GOOSE_LIFETIME: f32 = 8.0;
+const GOOSE_SPEED: f32 = 450.0; // Fast enough to catch a seed during its 0.4s flight
+const GOOSE_EAT_DISTANCE: f32 = 20.0;
+const GOOSE_SCALE: f32 = 0.25; // Scale 175px sprite to about 44px
 const SPAWN_MARGIN: f32 = 50.0;
-const TOP_UI_HEIGHT_RATIO: f32 = 0.12;
-const BOTTOM_UI_HEIGHT_RATIO: f32 = 0.08;
+const RABBIT_SEARCH_RADIUS: f32 = 200.0; // Initial target search radius, doubled until the best target is certain
 
 /// Component to track sound effect duration
 #[derive(Component)]
@@ -41,18 +68,26 @@ pub struct PowerupsPlugin;
 impl Plugin for PowerupsPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(GameState::Playing), setup_powerup_resources)
+            .add_systems(
+                PreUpdate,
+                setup_powerup_resources.run_if(in_state(GameState::Playing)).run_if(on_event::<LevelStartEvent>),
+            )
             .insert_resource(FireManager::new())
+            .add_event::<PowerupSpentEvent>()
             .add_systems(
                 Update,
                 (
-                    spawn_powerups,
+                    spawn_powerups.run_if(level_not_failed),
                     handle_powerup_clicks,
+                    deploy_stockpiled_powerups.run_if(level_not_failed),
+                    update_powerup_slots.after(deploy_stockpiled_powerups),
                     update_powerup_effects,
-                    #[cfg(feature = "debug_keys")]
-                    handle_debug_keys,
-                    update_rabbits,
+                    update_rabbits.after(RebuildDandelionGrid).after(CollectPowerupAreas),
                     update_rabbit_sprites,
-                    update_fire_system,
+                    update_fire_system.after(RebuildDandelionGrid),
+                    update_poison_zones.after(RebuildDandelionGrid),
+                    update_lawn_mowers.after(RebuildDandelionGrid).before(MoveSeedOrbs),
+                    update_geese.before(MoveSeedOrbs),
                     cleanup_expired_entities,
                     update_sound_timers,
                 )
@@ -60,6 +95,14 @@ impl Plugin for PowerupsPlugin {
                     .run_if(in_state(PauseState::Playing)),
             )
             .add_systems(OnExit(GameState::Playing), cleanup_powerups);
+
+        #[cfg(feature = "debug_keys")]
+        app.add_observer(debug_spawn_fire)
+            .add_observer(debug_spawn_rabbits)
+            .add_observer(debug_spawn_weed_killer)
+            .add_observer(debug_spawn_lawn_mower)
+            .add_observer(debug_spawn_geese)
+            .add_observer(debug_spawn_dandelion_ring);
     }
 }
 
@@ -78,24 +121,261 @@ impl Default for PowerupSpawnTimer {
 }
 
 /// Types of powerups available
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum PowerupType {
     Bunny,
     Flamethrower,
+    WeedKiller,
+    LawnMower,
+    Goose,
 }
 
 impl PowerupType {
     /// Get all available powerup types
     pub fn all() -> Vec<Self> {
-        vec![PowerupType::Bunny, PowerupType::Flamethrower]
+        vec![
+            PowerupType::Bunny,
+            PowerupType::Flamethrower,
+            PowerupType::WeedKiller,
+            PowerupType::LawnMower,
+            PowerupType::Goose,
+        ]
     }
 
     /// Get a random powerup type
-    pub fn random() -> Self {
-        let mut rng = rand::thread_rng();
+    pub fn random(rng: &mut impl Rng) -> Self {
         let powerups = Self::all();
         powerups[rng.gen_range(0..powerups.len())]
     }
+
+    /// Sprite shown for this powerup on the lawn and in its inventory slot
+    pub fn image(self, assets: &GameAssets) -> Handle<Image> {
+        match self {
+            PowerupType::Bunny => assets.bunny.clone(),
+            PowerupType::Flamethrower => assets.flamethrower.clone(),
+            PowerupType::WeedKiller => assets.weed_killer.clone(),
+            PowerupType::LawnMower => assets.lawn_mower.clone(),
+            PowerupType::Goose => assets.goose.clone(),
+        }
+    }
+
+    /// Scale of this powerup's sprite on the lawn
+    fn scale(self) -> f32 {
+        match self {
+            PowerupType::Bunny => RABBIT_SCALE,
+            PowerupType::Flamethrower => FLAMETHROWER_SCALE,
+            PowerupType::WeedKiller => WEED_KILLER_SCALE,
+            PowerupType::LawnMower => LAWN_MOWER_SCALE,
+            PowerupType::Goose => GOOSE_SCALE,
+        }
+    }
+}
+
+/// Powerup charges earned from stars, kept between levels and sessions
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PowerupInventory {
+    charges: BTreeMap<PowerupType, u32>,
+    /// Index into `PowerupType::all()` of the type the next star grants, so earned charges stay balanced
+    next_grant: usize,
+}
+
+impl PowerupInventory {
+    /// Charges left of a powerup type
+    pub fn charges(&self, powerup_type: PowerupType) -> u32 {
+        self.charges.get(&powerup_type).copied().unwrap_or(0)
+    }
+
+    /// Add charges directly, e.g. for tests or rewards outside of stars
+    pub fn add(&mut self, powerup_type: PowerupType, count: u32) {
+        let charges = self.charges.entry(powerup_type).or_default();
+        *charges = charges.saturating_add(count);
+    }
+
+    /// Grant one charge per star, cycling through the powerup types
+    pub fn grant_stars(&mut self, stars: u32) {
+        let powerups = PowerupType::all();
+        for _ in 0..stars {
+            self.add(powerups[self.next_grant % powerups.len()], 1);
+            self.next_grant = (self.next_grant + 1) % powerups.len();
+        }
+    }
+
+    /// Use up one charge, returning false if there are none left
+    pub fn spend(&mut self, powerup_type: PowerupType) -> bool {
+        match self.charges.get_mut(&powerup_type) {
+            Some(charges) if *charges > 0 => {
+                *charges -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Permanent powerup improvements bought with stars
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Upgrade {
+    RabbitCount,
+    RabbitLifetime,
+    RabbitSpeed,
+    RabbitAppetite,
+    FireRadius,
+    FireChain,
+    FireLifetime,
+}
+
+impl Upgrade {
+    /// Get all upgrades in the order they appear on the upgrades screen
+    pub fn all() -> Vec<Self> {
+        vec![
+            Upgrade::RabbitCount,
+            Upgrade::RabbitLifetime,
+            Upgrade::RabbitSpeed,
+            Upgrade::RabbitAppetite,
+            Upgrade::FireRadius,
+            Upgrade::FireChain,
+            Upgrade::FireLifetime,
+        ]
+    }
+
+    /// Powerup this upgrade improves
+    pub fn powerup_type(self) -> PowerupType {
+        match self {
+            Upgrade::RabbitCount | Upgrade::RabbitLifetime | Upgrade::RabbitSpeed | Upgrade::RabbitAppetite => PowerupType::Bunny,
+            Upgrade::FireRadius | Upgrade::FireChain | Upgrade::FireLifetime => PowerupType::Flamethrower,
+        }
+    }
+
+    /// Name shown on the upgrades screen
+    pub fn name(self) -> &'static str {
+        match self {
+            Upgrade::RabbitCount => "Bigger Litters",
+            Upgrade::RabbitLifetime => "Patient Rabbits",
+            Upgrade::RabbitSpeed => "Fast Rabbits",
+            Upgrade::RabbitAppetite => "Eager Breeders",
+            Upgrade::FireRadius => "Wider Flames",
+            Upgrade::FireChain => "Wildfire",
+            Upgrade::FireLifetime => "Slow Burn",
+        }
+    }
+
+    /// Highest level the upgrade can reach
+    pub fn max_level(self) -> u32 {
+        match self {
+            // Rabbits can't multiply any sooner than after their first dandelion
+            Upgrade::RabbitAppetite => RABBIT_LITTER_AFTER - 1,
+            _ => 3,
+        }
+    }
+
+    /// Stars needed to buy the level after `level`
+    pub fn cost(self, level: u32) -> u32 {
+        level + 1
+    }
+}
+
+/// Upgrade levels bought with stars, kept between levels and sessions
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PowerupUpgrades {
+    levels: BTreeMap<Upgrade, u32>,
+    stars_spent: u32,
+}
+
+impl PowerupUpgrades {
+    /// Current level of an upgrade, 0 if never bought
+    pub fn level(&self, upgrade: Upgrade) -> u32 {
+        self.levels.get(&upgrade).copied().unwrap_or(0)
+    }
+
+    /// Stars spent on upgrades so far
+    pub fn stars_spent(&self) -> u32 {
+        self.stars_spent
+    }
+
+    /// Stars needed for the next level of an upgrade, or `None` once it is maxed out
+    pub fn next_cost(&self, upgrade: Upgrade) -> Option<u32> {
+        let level = self.level(upgrade);
+        (level < upgrade.max_level()).then(|| upgrade.cost(level))
+    }
+
+    /// Buy the next level of an upgrade out of `available_stars`, returning false if it is maxed out or too expensive
+    pub fn buy(&mut self, upgrade: Upgrade, available_stars: u32) -> bool {
+        match self.next_cost(upgrade) {
+            Some(cost) if cost <= available_stars => {
+                *self.levels.entry(upgrade).or_default() += 1;
+                self.stars_spent += cost;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Rabbits released by one bunny powerup, and by each rabbit that multiplies
+    pub fn rabbit_count(&self) -> u32 {
+        RABBIT_COUNT + self.level(Upgrade::RabbitCount)
+    }
+
+    /// Seconds a rabbit hunts before leaving
+    pub fn rabbit_lifetime(&self) -> f32 {
+        RABBIT_LIFETIME + self.level(Upgrade::RabbitLifetime) as f32
+    }
+
+    /// Rabbit running speed in pixels per second
+    pub fn rabbit_speed(&self) -> f32 {
+        RABBIT_SPEED + 30.0 * self.level(Upgrade::RabbitSpeed) as f32
+    }
+
+    /// Dandelions a rabbit eats before it multiplies
+    pub fn rabbit_litter_after(&self) -> u32 {
+        RABBIT_LITTER_AFTER.saturating_sub(self.level(Upgrade::RabbitAppetite)).max(1)
+    }
+
+    /// Radius a fire burns dandelions in
+    pub fn fire_radius(&self) -> f32 {
+        FIRE_RADIUS + 20.0 * self.level(Upgrade::FireRadius) as f32
+    }
+
+    /// Deepest generation a fire chain reaction can reach
+    pub fn fire_max_generation(&self) -> u32 {
+        FireManager::MAX_GENERATION + self.level(Upgrade::FireChain)
+    }
+
+    /// Seconds a fire burns before going out
+    pub fn fire_lifetime(&self) -> f32 {
+        FIRE_LIFETIME + self.level(Upgrade::FireLifetime) as f32
+    }
+
+    /// Current value of an upgrade, formatted for the upgrades screen
+    pub fn describe(&self, upgrade: Upgrade) -> String {
+        match upgrade {
+            Upgrade::RabbitCount => format!("{} rabbits", self.rabbit_count()),
+            Upgrade::RabbitLifetime => format!("{:.0}s hunt", self.rabbit_lifetime()),
+            Upgrade::RabbitSpeed => format!("{:.0} speed", self.rabbit_speed()),
+            Upgrade::RabbitAppetite => format!("multiply after {} eaten", self.rabbit_litter_after()),
+            Upgrade::FireRadius => format!("{:.0}px radius", self.fire_radius()),
+            Upgrade::FireChain => format!("{} chain steps", self.fire_max_generation()),
+            Upgrade::FireLifetime => format!("{:.0}s burn", self.fire_lifetime()),
+        }
+    }
+}
+
+/// Inventory powerup waiting for a tap on the lawn, selected from its HUD slot
+#[derive(Resource, Default)]
+pub struct ArmedPowerup(pub Option<PowerupType>);
+
+/// HUD button that arms a powerup from the inventory
+#[derive(Component)]
+pub struct PowerupSlot(pub PowerupType);
+
+/// Text showing how many charges a HUD slot has left
+#[derive(Component)]
+pub struct PowerupSlotCount(pub PowerupType);
+
+/// Sent when an inventory charge is used, so the inventory can be saved
+#[derive(Event)]
+pub struct PowerupSpentEvent {
+    pub powerup_type: PowerupType,
+    pub position: Vec2,
 }
 
 /// Component for powerup entities
@@ -159,16 +439,30 @@ impl RabbitTargeting {
 pub struct Rabbit {
     target: Option<Entity>,
     dandelions_eaten: u32,
+    litter_after: u32,
     lifetime: Timer,
     speed: f32,
     facing_right: bool, // Track movement direction for sprite flipping
 }
 
+impl Rabbit {
+    /// A rabbit with the player's upgrades applied
+    fn upgraded(upgrades: &PowerupUpgrades) -> Self {
+        Self {
+            litter_after: upgrades.rabbit_litter_after(),
+            lifetime: Timer::from_seconds(upgrades.rabbit_lifetime(), TimerMode::Once),
+            speed: upgrades.rabbit_speed(),
+            ..default()
+        }
+    }
+}
+
 impl Default for Rabbit {
     fn default() -> Self {
         Self {
             target: None,
             dandelions_eaten: 0,
+            litter_after: RABBIT_LITTER_AFTER,
             lifetime: Timer::from_seconds(RABBIT_LIFETIME, TimerMode::Once),
             speed: RABBIT_SPEED,
             facing_right: false, // Default faces left (original sprite direction)
@@ -176,13 +470,80 @@ impl Default for Rabbit {
     }
 }
 
+/// Resource to track seed orb targeting so several geese don't chase the same seed
+#[derive(Resource, Default)]
+pub struct GooseTargeting {
+    /// Maps seed orb entity to the goose entity chasing it
+    pub targets: HashMap<Entity, Entity>,
+}
+
+impl GooseTargeting {
+    /// Reserve a seed orb for a specific goose
+    fn claim_target(&mut self, goose: Entity, seed_orb: Entity) {
+        self.targets.insert(seed_orb, goose);
+    }
+
+    /// Check if a seed orb is claimed by a goose other than `goose`
+    fn is_claimed_by_other(&self, seed_orb: Entity, goose: Entity) -> bool {
+        self.targets.get(&seed_orb).is_some_and(|&claimed_by| claimed_by != goose)
+    }
+
+    /// Remove a target claim (when the seed is eaten or lands)
+    fn release_target(&mut self, seed_orb: Entity) {
+        self.targets.remove(&seed_orb);
+    }
+
+    /// Clear all targets for a specific goose (when the goose leaves)
+    fn clear_goose_targets(&mut self, goose: Entity) {
+        self.targets.retain(|_, &mut chasing_goose| chasing_goose != goose);
+    }
+
+    /// Clear all targets (used during cleanup to prevent memory leaks)
+    fn clear(&mut self) {
+        self.targets.clear();
+    }
+}
+
+/// Component for geese that catch seed orbs in flight
+#[derive(Component)]
+pub struct Goose {
+    target: Option<Entity>,
+    seeds_eaten: u32,
+    lifetime: Timer,
+    facing_right: bool, // Track movement direction for sprite flipping
+}
+
+impl Default for Goose {
+    fn default() -> Self {
+        Self {
+            target: None,
+            seeds_eaten: 0,
+            lifetime: Timer::from_seconds(GOOSE_LIFETIME, TimerMode::Once),
+            facing_right: false, // Default faces left (original sprite direction)
+        }
+    }
+}
+
 /// Component for fire ignition entities
 #[derive(Component)]
 pub struct FireIgnition {
     radius: f32,
     damage_timer: Timer,
     lifetime: Timer,
-    generation: u32, // Track fire generation to limit chain reactions
+    generation: u32,        // Track fire generation to limit chain reactions
+    extra_generations: u32, // Chain depth added by combos, inherited by the whole chain
+}
+
+impl FireIgnition {
+    /// How many fires this one is removed from the original flamethrower
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// Radius the fire burns dandelions in
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
 }
 
 impl Default for FireIgnition {
@@ -192,10 +553,49 @@ impl Default for FireIgnition {
             damage_timer: Timer::from_seconds(0.2, TimerMode::Repeating),
             lifetime: Timer::from_seconds(FIRE_LIFETIME, TimerMode::Once),
             generation: 0,
+            extra_generations: 0,
+        }
+    }
+}
+
+/// Component for the lingering area left by the weed killer spray
+#[derive(Component)]
+pub struct PoisonZone {
+    radius: f32,
+    damage_timer: Timer,
+    lifetime: Timer,
+}
+
+impl PoisonZone {
+    /// Radius of the poisoned area
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Whether `point` is inside a zone centered on `center`
+    pub fn covers(&self, center: Vec2, point: Vec2) -> bool {
+        center.distance_squared(point) <= self.radius * self.radius
+    }
+}
+
+impl Default for PoisonZone {
+    fn default() -> Self {
+        Self {
+            radius: POISON_RADIUS,
+            damage_timer: Timer::from_seconds(POISON_DAMAGE_INTERVAL, TimerMode::Repeating),
+            lifetime: Timer::from_seconds(POISON_LIFETIME, TimerMode::Once),
         }
     }
 }
 
+/// Component for a lawn mower driving across the lawn in a straight lane
+#[derive(Component)]
+pub struct LawnMower {
+    direction: Vec2,
+    /// Dandelions already cut, so each is only damaged once
+    cut: HashSet<Entity>,
+}
+
 /// Resource to efficiently track active fires and batch damage calculations
 #[derive(Resource, Default)]
 pub struct FireManager {
@@ -212,15 +612,17 @@ pub struct FireData {
     position: Vec2,
     radius: f32,
     generation: u32,
+    extra_generations: u32,
 }
 
 pub struct PendingFire {
     position: Vec2,
     generation: u32,
+    extra_generations: u32,
 }
 
 impl FireManager {
-    const MAX_GENERATION: u32 = 5; // Limit chain reaction depth
+    pub const MAX_GENERATION: u32 = 5; // Limit chain reaction depth before upgrades
     const BATCH_INTERVAL: f32 = 0.05; // Process fires every 50ms for faster spreading
 
     fn new() -> Self {
@@ -258,141 +660,161 @@ struct FirePreview;
 /// Setup powerup resources including timer and targeting
 fn setup_powerup_resources(mut commands: Commands) {
     commands.insert_resource(PowerupSpawnTimer::default());
+    commands.insert_resource(ArmedPowerup::default());
     commands.insert_resource(RabbitTargeting::default());
+    commands.insert_resource(GooseTargeting::default());
     commands.insert_resource(FireManager::new());
 }
 
 /// Spawn powerups at random positions
-fn spawn_powerups(mut commands: Commands, mut spawn_timer: ResMut<PowerupSpawnTimer>, time: Res<Time>, windows: Query<&Window>, assets: Res<GameAssets>) {
+fn spawn_powerups(
+    mut commands: Commands,
+    mut spawn_timer: ResMut<PowerupSpawnTimer>,
+    time: Res<Time>,
+    play_area: Res<PlayArea>,
+    assets: Res<GameAssets>,
+    mut rng: ResMut<GameRng>,
+) {
     spawn_timer.timer.tick(time.delta());
 
     if spawn_timer.timer.just_finished() {
-        if let Ok(window) = windows.single() {
-            let position = calculate_random_spawn_position(window);
-            let powerup_type = PowerupType::random();
-            spawn_powerup_with_effect(&mut commands, &assets, position, powerup_type);
-            debug!("Spawned {:?} powerup at ({:.1}, {:.1})", powerup_type, position.x, position.y);
-        }
+        let position = calculate_random_spawn_position(&play_area, &mut *rng);
+        let powerup_type = PowerupType::random(&mut *rng);
+        spawn_powerup_with_effect(&mut commands, &assets, position, powerup_type);
+        debug!("Spawned {:?} powerup at ({:.1}, {:.1})", powerup_type, position.x, position.y);
     }
 }
 
 /// Calculate a random spawn position within safe boundaries
-fn calculate_random_spawn_position(window: &Window) -> Vec2 {
-    let mut rng = rand::thread_rng();
-
-    let top_ui_height = window.height() * TOP_UI_HEIGHT_RATIO;
-    let bottom_ui_height = window.height() * BOTTOM_UI_HEIGHT_RATIO;
-
-    let min_x = -window.width() / 2.0 + SPAWN_MARGIN;
-    let max_x = window.width() / 2.0 - SPAWN_MARGIN;
-    let min_y = -window.height() / 2.0 + bottom_ui_height + SPAWN_MARGIN;
-    let max_y = window.height() / 2.0 - top_ui_height - SPAWN_MARGIN;
-
-    Vec2::new(rng.gen_range(min_x..max_x), rng.gen_range(min_y..max_y))
+fn calculate_random_spawn_position(play_area: &PlayArea, rng: &mut impl Rng) -> Vec2 {
+    let bounds = play_area.grass_bounds(SPAWN_MARGIN);
+    Vec2::new(rng.gen_range(bounds.min.x..bounds.max.x), rng.gen_range(bounds.min.y..bounds.max.y))
 }
 
 /// Spawn a powerup with its visual effect
 fn spawn_powerup_with_effect(commands: &mut Commands, assets: &GameAssets, position: Vec2, powerup_type: PowerupType) {
     // Spawn the powerup
-    let image_handle = match powerup_type {
-        PowerupType::Bunny => assets.bunny.clone(),
-        PowerupType::Flamethrower => assets.flamethrower.clone(),
-    };
     commands.spawn((
         Sprite {
-            image: image_handle,
+            image: powerup_type.image(assets),
             ..default()
         },
-        Transform::from_translation(Vec3::new(position.x, position.y, 15.0)).with_scale(Vec3::splat(if powerup_type == PowerupType::Bunny {
-            RABBIT_SCALE
-        } else {
-            FLAMETHROWER_SCALE
-        })),
+        Transform::from_translation(Vec3::new(position.x, position.y, 15.0)).with_scale(Vec3::splat(powerup_type.scale())),
         Powerup { powerup_type },
         PowerupEntity,
     ));
 }
 
-/// Handle clicks and touches on powerups to trigger them immediately
+/// Handle clicks and touches on powerups to trigger them immediately, each touch on its own
 fn handle_powerup_clicks(
     mut commands: Commands,
-    mouse_input: Res<ButtonInput<MouseButton>>,
-    touches: Res<Touches>,
-    windows: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
+    player_input: Res<PlayerInput>,
     powerup_query: Query<(Entity, &Powerup, &Transform)>,
     assets: Res<GameAssets>,
+    level_data: Res<LevelData>,
 ) {
-    // Check for mouse click
-    let mouse_clicked = mouse_input.just_pressed(MouseButton::Left);
-
-    // Check for touch input (any finger touching)
-    let touch_started = touches.any_just_pressed();
-
-    if !mouse_clicked && !touch_started {
-        return;
-    }
-
-    // Get position from mouse or touch
-    let world_pos = if mouse_clicked {
-        get_world_click_position(&windows, &camera_query)
-    } else if touch_started {
-        get_world_touch_position(&windows, &camera_query, &touches)
-    } else {
-        None
-    };
-
-    let world_pos = match world_pos {
-        Some(pos) => pos,
-        None => return,
-    };
-
-    for (entity, powerup, transform) in powerup_query.iter() {
-        let powerup_pos = transform.translation.truncate();
-        let distance = world_pos.distance(powerup_pos);
-        if distance <= POWERUP_CLICK_RADIUS {
-            use_powerup(powerup.powerup_type, powerup_pos, &mut commands, &assets);
-            if let Ok(mut ec) = commands.get_entity(entity) {
-                ec.despawn();
+    let mut used = HashSet::new();
+
+    for world_pos in player_input.presses().chain(player_input.use_powerup) {
+        for (entity, powerup, transform) in powerup_query.iter() {
+            let powerup_pos = transform.translation.truncate();
+            let distance = world_pos.distance(powerup_pos);
+            // A powerup touched by several fingers at once only triggers once
+            if distance <= POWERUP_CLICK_RADIUS && used.insert(entity) {
+                use_powerup(powerup.powerup_type, powerup_pos, &mut commands, &assets, &level_data.upgrades);
+                if let Ok(mut ec) = commands.get_entity(entity) {
+                    ec.despawn();
+                }
+                debug!("Triggered {:?} powerup at ({:.1}, {:.1})", powerup.powerup_type, powerup_pos.x, powerup_pos.y);
+                break;
             }
-            debug!("Triggered {:?} powerup at ({:.1}, {:.1})", powerup.powerup_type, powerup_pos.x, powerup_pos.y);
-            break;
         }
     }
 }
 
-/// Convert screen click to world coordinates
-fn get_world_click_position(windows: &Query<&Window>, camera_query: &Query<(&Camera, &GlobalTransform)>) -> Option<Vec2> {
-    let window = windows.single().ok()?;
-    let (camera, camera_transform) = camera_query.single().ok()?;
-    let cursor_pos = window.cursor_position()?;
-    camera.viewport_to_world_2d(camera_transform, cursor_pos).ok()
-}
+/// Deploy the armed inventory powerup at the first press on the lawn, then apply any slot selection.
+/// Deploying first means the press that selects a slot can't also deploy it.
+fn deploy_stockpiled_powerups(
+    mut commands: Commands,
+    player_input: Res<PlayerInput>,
+    mut armed: ResMut<ArmedPowerup>,
+    mut level_data: ResMut<LevelData>,
+    play_area: Res<PlayArea>,
+    assets: Res<GameAssets>,
+    mut spent_events: EventWriter<PowerupSpentEvent>,
+) {
+    let lawn = play_area.grass_bounds(0.0);
+
+    if let Some(powerup_type) = armed.0
+        && let Some(position) = player_input.presses().find(|position| lawn.contains(*position))
+    {
+        if level_data.powerup_inventory.spend(powerup_type) {
+            use_powerup(powerup_type, position, &mut commands, &assets, &level_data.upgrades);
+            spent_events.write(PowerupSpentEvent { powerup_type, position });
+            debug!("Deployed {:?} from the inventory at ({:.1}, {:.1})", powerup_type, position.x, position.y);
+        }
+        armed.0 = None;
+    }
 
-/// Convert touch position to world coordinates
-fn get_world_touch_position(windows: &Query<&Window>, camera_query: &Query<(&Camera, &GlobalTransform)>, touches: &Touches) -> Option<Vec2> {
-    let _window = windows.single().ok()?;
-    let (camera, camera_transform) = camera_query.single().ok()?;
+    if let Some(powerup_type) = player_input.select_powerup {
+        // Selecting the armed slot again puts it away
+        armed.0 = if armed.0 == Some(powerup_type) || level_data.powerup_inventory.charges(powerup_type) == 0 {
+            None
+        } else {
+            Some(powerup_type)
+        };
+    }
+}
 
-    // Get the first touch that just started
-    let touch = touches.iter_just_pressed().next()?;
-    let touch_pos = touch.position();
+/// Show charge counts on the HUD slots and highlight the armed one
+fn update_powerup_slots(
+    armed: Res<ArmedPowerup>,
+    level_data: Res<LevelData>,
+    mut slots: Query<(&PowerupSlot, &mut BackgroundColor)>,
+    mut counts: Query<(&PowerupSlotCount, &mut Text)>,
+) {
+    if !armed.is_changed() && !level_data.is_changed() {
+        return;
+    }
 
-    camera.viewport_to_world_2d(camera_transform, touch_pos).ok()
+    for (slot, mut color) in slots.iter_mut() {
+        *color = BackgroundColor(if armed.0 == Some(slot.0) {
+            Color::srgb(0.8, 0.6, 0.1)
+        } else if level_data.powerup_inventory.charges(slot.0) == 0 {
+            Color::srgb(0.25, 0.25, 0.25)
+        } else {
+            Color::srgb(0.3, 0.4, 0.6)
+        });
+    }
+    for (count, mut text) in counts.iter_mut() {
+        text.0 = format!("x{}", level_data.powerup_inventory.charges(count.0));
+    }
 }
 
 /// Execute powerup effect at the specified location
-fn use_powerup(powerup_type: PowerupType, position: Vec2, commands: &mut Commands, assets: &GameAssets) {
+fn use_powerup(powerup_type: PowerupType, position: Vec2, commands: &mut Commands, assets: &GameAssets, upgrades: &PowerupUpgrades) {
     match powerup_type {
         PowerupType::Bunny => {
-            spawn_rabbits(commands, assets, position);
+            spawn_rabbits(commands, assets, position, upgrades);
             debug!("Bunny powerup activated at ({:.1}, {:.1})", position.x, position.y);
         }
         PowerupType::Flamethrower => {
-            spawn_fire_ignition(commands, assets, position);
+            spawn_fire_ignition(commands, assets, position, upgrades);
             play_flamethrower_sound(commands, assets);
             debug!("Flamethrower powerup activated at ({:.1}, {:.1})", position.x, position.y);
         }
+        PowerupType::WeedKiller => {
+            spawn_poison_zone(commands, assets, position);
+            debug!("Weed killer powerup activated at ({:.1}, {:.1})", position.x, position.y);
+        }
+        PowerupType::LawnMower => {
+            spawn_lawn_mower(commands, assets, position);
+            debug!("Lawn mower powerup activated at ({:.1}, {:.1})", position.x, position.y);
+        }
+        PowerupType::Goose => {
+            spawn_geese(commands, assets, position);
+            debug!("Goose powerup activated at ({:.1}, {:.1})", position.x, position.y);
+        }
     }
 }
 
@@ -417,43 +839,106 @@ fn update_powerup_effects(mut commands: Commands, mut effect_query: Query<(Entit
     }
 }
 
-/// Handle debug keys for testing - F for fire, B for bunny
+/// Where debug spawns appear: the cursor, or the center of the screen without one
+#[cfg(feature = "debug_keys")]
+fn debug_spawn_position(windows: &Query<&Window>, camera_query: &Query<(&Camera, &GlobalTransform)>) -> Vec2 {
+    crate::input::world_cursor_position(windows, camera_query).unwrap_or(Vec2::ZERO)
+}
+
+/// Debug key F: spawn fire at the cursor
 #[cfg(feature = "debug_keys")]
-fn handle_debug_keys(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+fn debug_spawn_fire(
+    _trigger: Trigger<Started<SpawnFire>>,
     mut commands: Commands,
     assets: Res<GameAssets>,
+    level_data: Res<LevelData>,
     windows: Query<&Window>,
     camera_query: Query<(&Camera, &GlobalTransform)>,
 ) {
-    let spawn_position = if let Some(world_pos) = get_world_click_position(&windows, &camera_query) {
-        // Use cursor position if available
-        world_pos
-    } else {
-        // Default to center of screen
-        Vec2::ZERO
-    };
+    let spawn_position = debug_spawn_position(&windows, &camera_query);
+    use_powerup(PowerupType::Flamethrower, spawn_position, &mut commands, &assets, &level_data.upgrades);
+    debug!("Debug: Spawned fire at ({:.1}, {:.1})", spawn_position.x, spawn_position.y);
+}
 
-    if keyboard_input.just_pressed(KeyCode::KeyF) {
-        use_powerup(PowerupType::Flamethrower, spawn_position, &mut commands, &assets);
-        debug!("Debug: Spawned fire at ({:.1}, {:.1})", spawn_position.x, spawn_position.y);
-    }
+/// Debug key B: spawn rabbits at the cursor
+#[cfg(feature = "debug_keys")]
+fn debug_spawn_rabbits(
+    _trigger: Trigger<Started<SpawnRabbits>>,
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    level_data: Res<LevelData>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    let spawn_position = debug_spawn_position(&windows, &camera_query);
+    use_powerup(PowerupType::Bunny, spawn_position, &mut commands, &assets, &level_data.upgrades);
+    debug!("Debug: Spawned bunny at ({:.1}, {:.1})", spawn_position.x, spawn_position.y);
+}
 
-    if keyboard_input.just_pressed(KeyCode::KeyB) {
-        use_powerup(PowerupType::Bunny, spawn_position, &mut commands, &assets);
-        debug!("Debug: Spawned bunny at ({:.1}, {:.1})", spawn_position.x, spawn_position.y);
-    }
+/// Debug key W: spray weed killer at the cursor
+#[cfg(feature = "debug_keys")]
+fn debug_spawn_weed_killer(
+    _trigger: Trigger<Started<SpawnWeedKiller>>,
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    level_data: Res<LevelData>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    let spawn_position = debug_spawn_position(&windows, &camera_query);
+    use_powerup(PowerupType::WeedKiller, spawn_position, &mut commands, &assets, &level_data.upgrades);
+    debug!("Debug: Sprayed weed killer at ({:.1}, {:.1})", spawn_position.x, spawn_position.y);
+}
 
-    if keyboard_input.just_pressed(KeyCode::KeyD) {
-        crate::enemies::spawn_dandelion_ring(&mut commands, &assets, spawn_position);
-        debug!("Debug: Spawned dandelion ring at ({:.1}, {:.1})", spawn_position.x, spawn_position.y);
-    }
+/// Debug key M: start a lawn mower at the cursor
+#[cfg(feature = "debug_keys")]
+fn debug_spawn_lawn_mower(
+    _trigger: Trigger<Started<SpawnLawnMower>>,
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    level_data: Res<LevelData>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    let spawn_position = debug_spawn_position(&windows, &camera_query);
+    use_powerup(PowerupType::LawnMower, spawn_position, &mut commands, &assets, &level_data.upgrades);
+    debug!("Debug: Started lawn mower at ({:.1}, {:.1})", spawn_position.x, spawn_position.y);
+}
+
+/// Debug key G: spawn geese at the cursor
+#[cfg(feature = "debug_keys")]
+fn debug_spawn_geese(
+    _trigger: Trigger<Started<SpawnGeese>>,
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    level_data: Res<LevelData>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    let spawn_position = debug_spawn_position(&windows, &camera_query);
+    use_powerup(PowerupType::Goose, spawn_position, &mut commands, &assets, &level_data.upgrades);
+    debug!("Debug: Spawned geese at ({:.1}, {:.1})", spawn_position.x, spawn_position.y);
+}
+
+/// Debug key D: spawn a dandelion ring around the cursor
+#[cfg(feature = "debug_keys")]
+fn debug_spawn_dandelion_ring(
+    _trigger: Trigger<Started<SpawnDandelionRing>>,
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    let spawn_position = debug_spawn_position(&windows, &camera_query);
+    crate::enemies::spawn_dandelion_ring(&mut commands, &assets, spawn_position);
+    debug!("Debug: Spawned dandelion ring at ({:.1}, {:.1})", spawn_position.x, spawn_position.y);
 }
 
-/// Spawn 3 rabbits at the specified location
-fn spawn_rabbits(commands: &mut Commands, assets: &GameAssets, position: Vec2) {
-    for i in 0..3 {
-        let angle = (i as f32) * (2.0 * std::f32::consts::PI / 3.0); // 120 degrees apart
+/// Spawn a litter of rabbits, spread evenly around the specified location
+fn spawn_rabbits(commands: &mut Commands, assets: &GameAssets, position: Vec2, upgrades: &PowerupUpgrades) {
+    let count = upgrades.rabbit_count();
+    for i in 0..count {
+        let angle = (i as f32) * (2.0 * std::f32::consts::PI / count as f32);
         let offset = Vec2::new(angle.cos(), angle.sin()) * 20.0;
         let spawn_pos = position + offset;
 
@@ -463,19 +948,26 @@ fn spawn_rabbits(commands: &mut Commands, assets: &GameAssets, position: Vec2) {
                 ..default()
             },
             Transform::from_translation(Vec3::new(spawn_pos.x, spawn_pos.y, 12.0)).with_scale(Vec3::splat(RABBIT_SCALE)),
-            Rabbit::default(),
+            Rabbit::upgraded(upgrades),
             PowerupEntity,
         ));
     }
 }
 
 /// Spawn fire ignition at the specified location
-fn spawn_fire_ignition(commands: &mut Commands, assets: &GameAssets, position: Vec2) {
-    spawn_fire_ignition_with_generation(commands, assets, position, 0);
+fn spawn_fire_ignition(commands: &mut Commands, assets: &GameAssets, position: Vec2, upgrades: &PowerupUpgrades) {
+    spawn_fire_ignition_with_generation(commands, assets, position, 0, 0, upgrades);
 }
 
 /// Spawn fire ignition with specific generation for chain reactions
-fn spawn_fire_ignition_with_generation(commands: &mut Commands, assets: &GameAssets, position: Vec2, generation: u32) {
+fn spawn_fire_ignition_with_generation(
+    commands: &mut Commands,
+    assets: &GameAssets,
+    position: Vec2,
+    generation: u32,
+    extra_generations: u32,
+    upgrades: &PowerupUpgrades,
+) {
     commands.spawn((
         Sprite {
             image: assets.flamethrower.clone(),
@@ -484,13 +976,68 @@ fn spawn_fire_ignition_with_generation(commands: &mut Commands, assets: &GameAss
         },
         Transform::from_translation(Vec3::new(position.x, position.y, 12.0)).with_scale(Vec3::splat(FLAMETHROWER_SCALE)),
         FireIgnition {
+            radius: upgrades.fire_radius(),
+            lifetime: Timer::from_seconds(upgrades.fire_lifetime(), TimerMode::Once),
             generation,
+            extra_generations,
             ..Default::default()
         },
         PowerupEntity,
     ));
 }
 
+/// Spawn a poison zone at the specified location
+fn spawn_poison_zone(commands: &mut Commands, assets: &GameAssets, position: Vec2) {
+    commands.spawn((
+        Sprite {
+            image: assets.weed_killer.clone(),
+            ..default()
+        },
+        // Below fires and rabbits, the zone itself is drawn as a circle by the playing UI
+        Transform::from_translation(Vec3::new(position.x, position.y, 11.0)).with_scale(Vec3::splat(WEED_KILLER_SCALE)),
+        PoisonZone::default(),
+        PowerupEntity,
+    ));
+}
+
+/// Spawn a lawn mower at the specified location, heading for the far side of the lawn
+fn spawn_lawn_mower(commands: &mut Commands, assets: &GameAssets, position: Vec2) {
+    let direction = if position.x <= 0.0 { Vec2::X } else { Vec2::NEG_X };
+    commands.spawn((
+        Sprite {
+            image: assets.lawn_mower.clone(),
+            // The sprite faces right
+            flip_x: direction.x < 0.0,
+            ..default()
+        },
+        Transform::from_translation(Vec3::new(position.x, position.y, 12.0)).with_scale(Vec3::splat(LAWN_MOWER_SCALE)),
+        LawnMower {
+            direction,
+            cut: HashSet::new(),
+        },
+        PowerupEntity,
+    ));
+}
+
+/// Spawn geese side by side at the specified location
+fn spawn_geese(commands: &mut Commands, assets: &GameAssets, position: Vec2) {
+    for i in 0..GOOSE_COUNT {
+        let offset = Vec2::new((i as f32 - (GOOSE_COUNT - 1) as f32 / 2.0) * 40.0, 0.0);
+        let spawn_pos = position + offset;
+
+        commands.spawn((
+            Sprite {
+                image: assets.goose.clone(),
+                ..default()
+            },
+            // Above seed orbs, so a goose is seen catching them
+            Transform::from_translation(Vec3::new(spawn_pos.x, spawn_pos.y, 16.0)).with_scale(Vec3::splat(GOOSE_SCALE)),
+            Goose::default(),
+            PowerupEntity,
+        ));
+    }
+}
+
 /// Component for tracking sprite animation state (future expansion)
 #[derive(Component)]
 struct RabbitSprite {
@@ -508,6 +1055,11 @@ fn update_rabbits(
     mut game_data: ResMut<GameData>,
     mut area_tracker: ResMut<DandelionAreaTracker>,
     mut rabbit_targeting: ResMut<RabbitTargeting>,
+    mut rng: ResMut<GameRng>,
+    grid: Res<DandelionGrid>,
+    level_data: Res<LevelData>,
+    areas: Res<PowerupAreas>,
+    mut traces: ResMut<PowerupTraces>,
 ) {
     // Clean up any invalid targets from the targeting resource
     let valid_dandelions: std::collections::HashSet<Entity> = dandelion_query.iter().map(|(e, _, _)| e).collect();
@@ -516,13 +1068,29 @@ fn update_rabbits(
     for (rabbit_entity, mut rabbit_transform, mut rabbit) in rabbit_query.iter_mut() {
         rabbit.lifetime.tick(time.delta());
 
+        // Run out of any area rabbits are afraid of before hunting again
+        let rabbit_pos = rabbit_transform.translation.truncate();
+        if let Some(threat) = areas.threat_to(PowerupType::Bunny, rabbit_pos) {
+            let direction = (rabbit_pos - threat).normalize_or(Vec2::X);
+            rabbit.facing_right = direction.x > 0.0;
+            rabbit_transform.translation += (direction * rabbit.speed * time.delta_secs()).extend(0.0);
+            continue;
+        }
+
         // Find optimal dandelion target if no current target or target is invalid
         if rabbit.target.is_none() || dandelion_query.get(rabbit.target.unwrap()).is_err() {
             if let Some(old_target) = rabbit.target {
                 rabbit_targeting.release_target(old_target);
             }
 
-            let new_target = find_best_dandelion_target(rabbit_entity, rabbit_transform.translation.truncate(), &dandelion_query, &rabbit_targeting);
+            let new_target = find_best_dandelion_target(
+                rabbit_entity,
+                rabbit_transform.translation.truncate(),
+                &dandelion_query,
+                &grid,
+                &rabbit_targeting,
+                &mut *rng,
+            );
 
             if let Some(target_entity) = new_target {
                 rabbit_targeting.claim_target(rabbit_entity, target_entity);
@@ -561,6 +1129,8 @@ fn update_rabbits(
                         &mut rabbit_targeting,
                         &mut game_data,
                         &mut area_tracker,
+                        &level_data.upgrades,
+                        &mut traces,
                     );
                 }
             }
@@ -580,6 +1150,8 @@ fn handle_rabbit_eating_dandelion(
     rabbit_targeting: &mut RabbitTargeting,
     game_data: &mut GameData,
     area_tracker: &mut DandelionAreaTracker,
+    upgrades: &PowerupUpgrades,
+    traces: &mut PowerupTraces,
 ) {
     // Play rabbit eating sound
     play_rabbit_sound(commands, assets);
@@ -592,24 +1164,25 @@ fn handle_rabbit_eating_dandelion(
 
     // Update game tracking
     area_tracker.total_area -= target_dandelion.size.visual_area();
-    game_data.add_dandelion_kill();
+    game_data.add_kill(target_dandelion.species);
     game_data.dandelion_count = game_data.dandelion_count.saturating_sub(1);
 
     rabbit.dandelions_eaten += 1;
     rabbit.target = None;
+    traces.leave(PowerupType::Bunny, rabbit_pos);
 
     let size_name = get_dandelion_size_name(target_dandelion.size);
 
     debug!("Rabbit ate a {} dandelion! Total eaten: {}", size_name, rabbit.dandelions_eaten);
 
-    // Rabbit reproduction after eating 2 dandelions
-    if rabbit.dandelions_eaten >= 2 {
-        spawn_rabbits(commands, assets, rabbit_pos);
+    // Rabbit reproduction once it has eaten its fill
+    if rabbit.dandelions_eaten >= rabbit.litter_after {
+        spawn_rabbits(commands, assets, rabbit_pos, upgrades);
         rabbit_targeting.clear_rabbit_targets(rabbit_entity);
         if let Ok(mut ec) = commands.get_entity(rabbit_entity) {
             ec.despawn();
         }
-        debug!("Rabbit spawned new rabbits after eating 2 dandelions!");
+        debug!("Rabbit spawned new rabbits after eating {} dandelions!", rabbit.dandelions_eaten);
     }
 }
 
@@ -640,54 +1213,76 @@ fn find_best_dandelion_target(
     rabbit_entity: Entity,
     rabbit_pos: Vec2,
     dandelion_query: &Query<(Entity, &Transform, &Dandelion), (With<Dandelion>, Without<Rabbit>)>,
+    grid: &DandelionGrid,
     rabbit_targeting: &RabbitTargeting,
+    rng: &mut impl Rng,
 ) -> Option<Entity> {
     let mut best_target = None;
     let mut best_score = f32::NEG_INFINITY;
 
-    // First pass: try to find untargeted dandelions (preferred)
-    for (dandelion_entity, dandelion_transform, dandelion) in dandelion_query.iter() {
-        // Skip if already being targeted by another rabbit
-        if rabbit_targeting.is_targeted(dandelion_entity) && rabbit_targeting.get_targeting_rabbit(dandelion_entity) != Some(rabbit_entity) {
-            continue;
-        }
+    // Distance beyond which the search covers every dandelion
+    let farthest = grid.bounds().map_or(0.0, |bounds| {
+        let corner = (rabbit_pos - bounds.center()).abs() + bounds.half_size();
+        corner.length()
+    });
+
+    // First pass: try to find untargeted dandelions (preferred), searching outwards
+    let mut radius = RABBIT_SEARCH_RADIUS;
+    loop {
+        for (dandelion_entity, dandelion_pos) in grid.query_radius(rabbit_pos, radius) {
+            // Skip if already being targeted by another rabbit
+            if rabbit_targeting.is_targeted(dandelion_entity) && rabbit_targeting.get_targeting_rabbit(dandelion_entity) != Some(rabbit_entity) {
+                continue;
+            }
+            let Ok((_, _, dandelion)) = dandelion_query.get(dandelion_entity) else {
+                continue;
+            };
+
+            let distance = rabbit_pos.distance(dandelion_pos);
 
-        let dandelion_pos = dandelion_transform.translation.truncate();
-        let distance = rabbit_pos.distance(dandelion_pos);
+            // Calculate score based on distance and dandelion size
+            let score = target_score(distance, dandelion.size);
 
-        // Calculate score based on distance and dandelion size
-        let size_bonus = get_dandelion_size_bonus(dandelion.size);
-        let score = (1000.0 / (distance + 1.0)) * size_bonus;
+            if score > best_score {
+                best_score = score;
+                best_target = Some(dandelion_entity);
+            }
+        }
 
-        if score > best_score {
-            best_score = score;
-            best_target = Some(dandelion_entity);
+        // Nothing further out can beat a huge dandelion right at the edge of the search
+        if best_score >= target_score(radius, DandelionSize::Huge) || radius >= farthest {
+            break;
         }
+        radius *= 2.0;
     }
 
     // If no untargeted dandelion found, fallback to random nearby dandelion
     if best_target.is_none() {
-        best_target = find_fallback_dandelion_target(rabbit_pos, dandelion_query);
+        best_target = find_fallback_dandelion_target(rabbit_pos, dandelion_query, grid, rng);
     }
 
     best_target
 }
 
-/// Find a fallback dandelion target when no optimal target is available  
+/// Targeting priority: closer and bigger dandelions first
+fn target_score(distance: f32, size: DandelionSize) -> f32 {
+    (1000.0 / (distance + 1.0)) * get_dandelion_size_bonus(size)
+}
+
+/// Find a fallback dandelion target when no optimal target is available
 fn find_fallback_dandelion_target(
     rabbit_pos: Vec2,
     dandelion_query: &Query<(Entity, &Transform, &Dandelion), (With<Dandelion>, Without<Rabbit>)>,
+    grid: &DandelionGrid,
+    rng: &mut impl Rng,
 ) -> Option<Entity> {
-    let close_dandelions: Vec<Entity> = dandelion_query
-        .iter()
-        .filter_map(|(entity, transform, _)| {
-            let distance = rabbit_pos.distance(transform.translation.truncate());
-            if distance <= 200.0 { Some(entity) } else { None }
-        })
+    let close_dandelions: Vec<Entity> = grid
+        .query_radius(rabbit_pos, 200.0)
+        .map(|(entity, _)| entity)
+        .filter(|entity| dandelion_query.contains(*entity))
         .collect();
 
     if !close_dandelions.is_empty() {
-        let mut rng = rand::thread_rng();
         let random_index = rng.gen_range(0..close_dandelions.len());
         Some(close_dandelions[random_index])
     } else {
@@ -705,6 +1300,9 @@ fn update_fire_system(
     assets: Res<GameAssets>,
     mut game_data: ResMut<GameData>,
     mut area_tracker: ResMut<DandelionAreaTracker>,
+    grid: Res<DandelionGrid>,
+    level_data: Res<LevelData>,
+    traces: Res<PowerupTraces>,
 ) {
     // Update fire manager timer (kept for potential future optimizations)
     fire_manager.batch_timer.tick(time.delta());
@@ -719,11 +1317,21 @@ fn update_fire_system(
         fire.damage_timer.tick(time.delta());
         fire.lifetime.tick(time.delta());
 
+        // Fires lit on a recent combo trace spread further
+        if fire.is_added() {
+            let extra = traces.extra_generations(PowerupType::Flamethrower, fire_transform.translation.truncate());
+            if extra > fire.extra_generations {
+                debug!("Fire combo: chain spreads {} more generations", extra);
+                fire.extra_generations = extra;
+            }
+        }
+
         // Store active fire data for batch processing
         fire_manager.active_fires.push(FireData {
             position: fire_transform.translation.truncate(),
             radius: fire.radius,
             generation: fire.generation,
+            extra_generations: fire.extra_generations,
         });
 
         // Fire visual effects
@@ -741,51 +1349,242 @@ fn update_fire_system(
 
     // Process fire damage every frame for immediate spreading
     let mut dandelions_to_destroy = Vec::new();
+    let mut burned = HashSet::new();
 
-    // Single pass through all dandelions, check against all fires
-    for (dandelion_entity, dandelion_transform, dandelion) in dandelion_query.iter() {
-        let dandelion_pos = dandelion_transform.translation.truncate();
-
-        // Check if this dandelion is hit by any fire
-        for fire_data in &fire_manager.active_fires {
-            let distance = fire_data.position.distance(dandelion_pos);
-            if distance <= fire_data.radius {
-                dandelions_to_destroy.push((dandelion_entity, dandelion_pos, dandelion.size, fire_data.generation));
-                break; // One hit is enough
+    // Each dandelion burns once, from the first fire that reaches it
+    for fire_data in &fire_manager.active_fires {
+        for (dandelion_entity, dandelion_pos) in grid.query_radius(fire_data.position, fire_data.radius) {
+            if !burned.insert(dandelion_entity) {
+                continue;
+            }
+            if let Ok((_, _, dandelion)) = dandelion_query.get(dandelion_entity) {
+                dandelions_to_destroy.push((
+                    dandelion_entity,
+                    dandelion_pos,
+                    dandelion.clone(),
+                    fire_data.generation,
+                    fire_data.extra_generations,
+                ));
             }
         }
     }
 
     // Process destroyed dandelions and queue chain fires
-    for (dandelion_entity, dandelion_pos, dandelion_size, generation) in dandelions_to_destroy {
+    for (dandelion_entity, dandelion_pos, dandelion, generation, extra_generations) in dandelions_to_destroy {
         // Remove the dandelion
         commands.entity(dandelion_entity).despawn();
 
         // Update tracking
-        area_tracker.total_area -= dandelion_size.visual_area();
-        game_data.add_dandelion_kill();
+        area_tracker.total_area -= dandelion.size.visual_area();
+        game_data.add_kill(dandelion.species);
         game_data.dandelion_count = game_data.dandelion_count.saturating_sub(1);
 
         // Queue chain fire if generation limit not exceeded
-        if generation < FireManager::MAX_GENERATION {
+        if generation < level_data.upgrades.fire_max_generation() + extra_generations {
             fire_manager.pending_fires.push(PendingFire {
                 position: dandelion_pos,
                 generation: generation + 1,
+                extra_generations,
             });
         }
     }
 
     // Spawn pending chain fires immediately for instant spreading
     for pending_fire in fire_manager.pending_fires.drain(..) {
-        spawn_fire_ignition_with_generation(&mut commands, &assets, pending_fire.position, pending_fire.generation);
+        spawn_fire_ignition_with_generation(
+            &mut commands,
+            &assets,
+            pending_fire.position,
+            pending_fire.generation,
+            pending_fire.extra_generations,
+            &level_data.upgrades,
+        );
+    }
+}
+
+/// Damage every dandelion inside a poison zone each tick, and fade the zone out over its lifetime
+fn update_poison_zones(
+    mut commands: Commands,
+    mut zone_query: Query<(&Transform, &mut PoisonZone, &mut Sprite)>,
+    mut dandelion_query: Query<&mut Dandelion>,
+    time: Res<Time>,
+    mut game_data: ResMut<GameData>,
+    mut area_tracker: ResMut<DandelionAreaTracker>,
+    grid: Res<DandelionGrid>,
+) {
+    for (zone_transform, mut zone, mut sprite) in zone_query.iter_mut() {
+        zone.damage_timer.tick(time.delta());
+        zone.lifetime.tick(time.delta());
+        sprite.color.set_alpha(1.0 - zone.lifetime.fraction());
+
+        if !zone.damage_timer.just_finished() {
+            continue;
+        }
+
+        for (dandelion_entity, dandelion_pos) in grid.query_radius(zone_transform.translation.truncate(), zone.radius) {
+            let Ok(mut dandelion) = dandelion_query.get_mut(dandelion_entity) else {
+                continue;
+            };
+            // Already killed by an overlapping zone this frame
+            if dandelion.health == 0 {
+                continue;
+            }
+
+            dandelion.health -= 1;
+            if dandelion.health == 0 {
+                destroy_dandelion(&mut commands, dandelion_entity, &dandelion, dandelion_pos, &mut game_data, &mut area_tracker);
+            }
+        }
+    }
+}
+
+/// Drive lawn mowers along their lane, cutting the dandelions and seed orbs they pass, until they leave the lawn
+fn update_lawn_mowers(
+    mut commands: Commands,
+    mut mower_query: Query<(Entity, &mut Transform, &mut LawnMower)>,
+    mut dandelion_query: Query<&mut Dandelion>,
+    seed_orbs: Query<(Entity, &Transform), (With<SeedOrb>, Without<LawnMower>)>,
+    time: Res<Time>,
+    play_area: Res<PlayArea>,
+    mut game_data: ResMut<GameData>,
+    mut area_tracker: ResMut<DandelionAreaTracker>,
+    grid: Res<DandelionGrid>,
+) {
+    let lawn = play_area.grass_bounds(0.0);
+
+    for (mower_entity, mut transform, mut mower) in mower_query.iter_mut() {
+        let start = transform.translation.truncate();
+        let end = start + mower.direction * LAWN_MOWER_SPEED * time.delta_secs();
+        transform.translation = end.extend(transform.translation.z);
+
+        let reach = Rect::from_corners(start, end).inflate(DandelionSize::max_collision_radius() + LAWN_MOWER_HALF_WIDTH);
+        for (dandelion_entity, dandelion_pos) in grid.query_rect(reach) {
+            let Ok(mut dandelion) = dandelion_query.get_mut(dandelion_entity) else {
+                continue;
+            };
+            if dandelion.health == 0 || mower.cut.contains(&dandelion_entity) {
+                continue;
+            }
+            if distance_point_to_line_segment(dandelion_pos, start, end) > dandelion.size.collision_radius() + LAWN_MOWER_HALF_WIDTH {
+                continue;
+            }
+
+            mower.cut.insert(dandelion_entity);
+            dandelion.health = dandelion.health.saturating_sub(LAWN_MOWER_DAMAGE);
+            if dandelion.health == 0 {
+                destroy_dandelion(&mut commands, dandelion_entity, &dandelion, dandelion_pos, &mut game_data, &mut area_tracker);
+            }
+        }
+
+        for (orb_entity, orb_transform) in seed_orbs.iter() {
+            if distance_point_to_line_segment(orb_transform.translation.truncate(), start, end) <= LAWN_MOWER_HALF_WIDTH {
+                commands.entity(orb_entity).try_despawn();
+            }
+        }
+
+        if !lawn.inflate(SPAWN_MARGIN).contains(end) {
+            commands.entity(mower_entity).despawn();
+            debug!("Lawn mower cut {} dandelions", mower.cut.len());
+        }
     }
 }
 
-/// Clean up expired entities (rabbits and fires)
+/// Chase and eat seed orbs, each goose after its own seed, until the geese fly off
+fn update_geese(
+    mut commands: Commands,
+    mut goose_query: Query<(Entity, &mut Transform, &mut Goose, &mut Sprite)>,
+    seed_orbs: Query<(Entity, &Transform), (With<SeedOrb>, Without<Goose>)>,
+    time: Res<Time>,
+    mut goose_targeting: ResMut<GooseTargeting>,
+) {
+    // Seeds that landed are no longer worth chasing
+    goose_targeting.targets.retain(|&seed_orb, _| seed_orbs.contains(seed_orb));
+
+    for (goose_entity, mut goose_transform, mut goose, mut sprite) in goose_query.iter_mut() {
+        goose.lifetime.tick(time.delta());
+        if goose.lifetime.just_finished() {
+            goose_targeting.clear_goose_targets(goose_entity);
+            commands.entity(goose_entity).despawn();
+            debug!("Goose flew off after eating {} seeds", goose.seeds_eaten);
+            continue;
+        }
+
+        let goose_pos = goose_transform.translation.truncate();
+
+        // Find the nearest seed no other goose is chasing
+        if goose.target.is_none_or(|target| !seed_orbs.contains(target)) {
+            goose.target = seed_orbs
+                .iter()
+                .filter(|(seed_orb, _)| !goose_targeting.is_claimed_by_other(*seed_orb, goose_entity))
+                .min_by(|(_, a), (_, b)| {
+                    let a = goose_pos.distance_squared(a.translation.truncate());
+                    let b = goose_pos.distance_squared(b.translation.truncate());
+                    a.total_cmp(&b)
+                })
+                .map(|(seed_orb, _)| seed_orb);
+
+            if let Some(seed_orb) = goose.target {
+                goose_targeting.claim_target(goose_entity, seed_orb);
+            }
+        }
+
+        let Some((seed_orb, seed_transform)) = goose.target.and_then(|target| seed_orbs.get(target).ok()) else {
+            continue;
+        };
+
+        let seed_pos = seed_transform.translation.truncate();
+        let direction = (seed_pos - goose_pos).normalize_or_zero();
+        if direction.x > 0.1 {
+            goose.facing_right = true;
+        } else if direction.x < -0.1 {
+            goose.facing_right = false;
+        }
+        // Original sprite faces left
+        sprite.flip_x = goose.facing_right;
+
+        // Don't overshoot a seed that is closer than a frame's flight
+        let step = (GOOSE_SPEED * time.delta_secs()).min(goose_pos.distance(seed_pos));
+        goose_transform.translation += (direction * step).extend(0.0);
+
+        if goose_transform.translation.truncate().distance(seed_pos) <= GOOSE_EAT_DISTANCE {
+            commands.entity(seed_orb).try_despawn();
+            goose_targeting.release_target(seed_orb);
+            goose.target = None;
+            goose.seeds_eaten += 1;
+        }
+    }
+}
+
+/// Kill a dandelion destroyed by a powerup, scattering its seeds like a player kill
+fn destroy_dandelion(
+    commands: &mut Commands,
+    entity: Entity,
+    dandelion: &Dandelion,
+    position: Vec2,
+    game_data: &mut GameData,
+    area_tracker: &mut DandelionAreaTracker,
+) {
+    commands.trigger_targets(
+        DandelionDeathEvent {
+            position,
+            size: dandelion.size,
+            species: dandelion.species,
+        },
+        entity,
+    );
+    commands.entity(entity).despawn();
+
+    area_tracker.total_area -= dandelion.size.visual_area();
+    game_data.add_kill(dandelion.species);
+    game_data.dandelion_count = game_data.dandelion_count.saturating_sub(1);
+}
+
+/// Clean up expired entities (rabbits, fires and poison zones)
 fn cleanup_expired_entities(
     mut commands: Commands,
     rabbit_query: Query<(Entity, &Rabbit)>,
     fire_query: Query<(Entity, &FireIgnition)>,
+    poison_query: Query<(Entity, &PoisonZone)>,
     mut rabbit_targeting: ResMut<RabbitTargeting>,
 ) {
     // Clean up expired rabbits
@@ -807,6 +1606,15 @@ fn cleanup_expired_entities(
             }
         }
     }
+
+    // Clean up expired poison zones
+    for (entity, zone) in poison_query.iter() {
+        if zone.lifetime.just_finished()
+            && let Ok(mut ec) = commands.get_entity(entity)
+        {
+            ec.despawn();
+        }
+    }
 }
 
 /// Play rabbit sound effect for limited duration
@@ -852,12 +1660,16 @@ fn cleanup_powerups(
     mut commands: Commands,
     powerup_entities: Query<Entity, With<PowerupEntity>>,
     rabbit_targeting: Option<ResMut<RabbitTargeting>>,
+    goose_targeting: Option<ResMut<GooseTargeting>>,
     fire_manager: Option<ResMut<FireManager>>,
 ) {
     // Clear rabbit targeting HashMap before removing resource
     if let Some(mut targeting) = rabbit_targeting {
         targeting.clear();
     }
+    if let Some(mut targeting) = goose_targeting {
+        targeting.clear();
+    }
 
     // Clear fire manager before removing resource
     if let Some(mut fire_mgr) = fire_manager {
@@ -865,7 +1677,9 @@ fn cleanup_powerups(
     }
 
     commands.remove_resource::<PowerupSpawnTimer>();
+    commands.remove_resource::<ArmedPowerup>();
     commands.remove_resource::<RabbitTargeting>();
+    commands.remove_resource::<GooseTargeting>();
     commands.remove_resource::<FireManager>();
 
     for entity in &powerup_entities {
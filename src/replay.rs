@@ -0,0 +1,433 @@
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::GameState;
+use crate::input::{PlayerInput, Pointer, PointerId};
+use crate::levels::{FailureReason, LevelCompleteEvent, LevelData, LevelFailedEvent, LevelStartEvent};
+use crate::pause_menu::PauseState;
+use crate::play_area::PlayArea;
+use crate::playing::{GameData, setup_game_resources};
+use crate::powerups::{PowerupInventory, PowerupType, PowerupUpgrades};
+use crate::rng::GameRng;
+
+/// Directory completed runs are recorded to, next to the progress save
+const REPLAY_DIR: &str = "save/replays";
+
+/// Bump whenever the layout of `Replay` changes in a non-compatible way
+const REPLAY_VERSION: u32 = 6;
+
+/// Plugin that records every level run and can play a recorded run back
+pub struct ReplayPlugin {
+    /// Replay loaded from the command line (`--replay <FILE>`)
+    pub replay: Option<Replay>,
+}
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayRecorder>()
+            .add_systems(
+                OnEnter(GameState::Playing),
+                begin_recording_on_enter.after(setup_game_resources).run_if(not(is_replaying)),
+            )
+            .add_systems(
+                PreUpdate,
+                begin_recording_on_level_start.run_if(in_state(GameState::Playing)).run_if(not(is_replaying)),
+            )
+            .add_systems(
+                PostUpdate,
+                (record_frame, finish_recording)
+                    .chain()
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(in_state(PauseState::Playing))
+                    .run_if(not(is_replaying)),
+            )
+            .add_systems(OnExit(GameState::Playing), discard_recording);
+
+        if let Some(replay) = &self.replay {
+            // Every frame until the level starts uses the first recorded time step
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(replay.frame_times[0]))
+                .insert_resource(ReplayPlayback {
+                    replay: replay.clone(),
+                    frame: 0,
+                    finished: false,
+                    player_inventory: None,
+                    player_upgrades: None,
+                })
+                .add_systems(Startup, start_replay)
+                .add_systems(OnEnter(GameState::Playing), apply_replay_start.after(setup_game_resources).run_if(is_replaying))
+                .add_systems(OnExit(GameState::Playing), stop_replay.run_if(is_replaying))
+                .add_systems(PreUpdate, feed_replay_input.after(InputSystem).run_if(is_replaying))
+                .add_systems(
+                    PostUpdate,
+                    (check_replay_result, advance_replay)
+                        .chain()
+                        .run_if(in_state(GameState::Playing))
+                        .run_if(in_state(PauseState::Playing))
+                        .run_if(is_replaying),
+                );
+        }
+    }
+}
+
+/// A recorded level run: everything needed to play it back frame for frame
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Replay {
+    version: u32,
+    pub level_id: u32,
+    pub seed: u64,
+    /// Play area size the run was played at, since it bounds where things spawn
+    pub window_size: (f32, f32),
+    pub slash_mode: bool,
+    /// Powerup charges the run started with
+    pub powerup_inventory: PowerupInventory,
+    /// Upgrades bought when the run started, since they change how powerups behave
+    pub upgrades: PowerupUpgrades,
+    /// Time step of every gameplay frame, in order
+    pub frame_times: Vec<Duration>,
+    pub inputs: Vec<RecordedInput>,
+    pub score: u32,
+    pub completion_time: Duration,
+}
+
+/// A player action and the gameplay frame it happened on
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RecordedInput {
+    pub frame: u32,
+    pub action: ReplayAction,
+}
+
+/// Recordable player actions
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum ReplayAction {
+    /// A click or touch, as in `Pointer`
+    Pointer {
+        id: PointerId,
+        position: Option<(f32, f32)>,
+        just_pressed: bool,
+        just_released: bool,
+    },
+    ToggleSlashMode,
+    /// Trigger the field powerup at a world position, as in `PlayerInput::use_powerup`
+    UsePowerup {
+        position: (f32, f32),
+    },
+    /// Tap an inventory slot, as in `PlayerInput::select_powerup`
+    SelectPowerup {
+        powerup: PowerupType,
+    },
+}
+
+/// Run being recorded, if a level is in progress
+#[derive(Resource, Default)]
+struct ReplayRecorder {
+    recording: Option<Replay>,
+}
+
+/// Replay being played back
+#[derive(Resource)]
+pub struct ReplayPlayback {
+    replay: Replay,
+    frame: usize,
+    finished: bool,
+    /// The player's own charges, held back while the run plays with the recorded ones
+    player_inventory: Option<PowerupInventory>,
+    /// The player's own upgrades, held back likewise
+    player_upgrades: Option<PowerupUpgrades>,
+}
+
+impl ReplayPlayback {
+    /// Stop playing back and hand the player's own charges and upgrades back
+    fn finish(&mut self, level_data: &mut LevelData) {
+        self.finished = true;
+        if let Some(inventory) = self.player_inventory.take() {
+            level_data.powerup_inventory = inventory;
+        }
+        if let Some(upgrades) = self.player_upgrades.take() {
+            level_data.upgrades = upgrades;
+        }
+    }
+}
+
+/// Run condition: true while a replay is feeding input instead of the player
+pub fn is_replaying(playback: Option<Res<ReplayPlayback>>) -> bool {
+    playback.is_some_and(|playback| !playback.finished)
+}
+
+/// Parse `--replay <FILE>` from the command line arguments and load the replay
+pub fn load_replay_arg(args: &[String]) -> Result<Option<Replay>, String> {
+    let Some(position) = args.iter().position(|arg| arg == "--replay") else {
+        return Ok(None);
+    };
+
+    let path = args.get(position + 1).ok_or("--replay needs a file")?;
+    let contents = std::fs::read_to_string(path).map_err(|err| format!("could not read replay {}: {}", path, err))?;
+    let replay: Replay = ron::from_str(&contents).map_err(|err| format!("replay {} is not valid: {}", path, err))?;
+
+    if replay.version != REPLAY_VERSION {
+        return Err(format!(
+            "replay {} has unsupported version {} (expected {})",
+            path, replay.version, REPLAY_VERSION
+        ));
+    }
+    if replay.frame_times.is_empty() {
+        return Err(format!("replay {} has no frames", path));
+    }
+
+    Ok(Some(replay))
+}
+
+/// Start a new recording for the level the game is entered on
+fn begin_recording_on_enter(mut recorder: ResMut<ReplayRecorder>, level_data: Res<LevelData>, game_data: Res<GameData>) {
+    recorder.recording = Some(new_recording(level_data.current_level, &level_data, game_data.slash_mode));
+}
+
+/// Start a new recording whenever a level is started or retried
+fn begin_recording_on_level_start(
+    mut level_start_events: EventReader<LevelStartEvent>,
+    mut recorder: ResMut<ReplayRecorder>,
+    level_data: Res<LevelData>,
+    game_data: Res<GameData>,
+) {
+    for event in level_start_events.read() {
+        recorder.recording = Some(new_recording(event.level_id, &level_data, game_data.slash_mode));
+    }
+}
+
+fn new_recording(level_id: u32, level_data: &LevelData, slash_mode: bool) -> Replay {
+    Replay {
+        version: REPLAY_VERSION,
+        level_id,
+        seed: 0,
+        window_size: (0.0, 0.0),
+        slash_mode,
+        powerup_inventory: level_data.powerup_inventory.clone(),
+        upgrades: level_data.upgrades.clone(),
+        frame_times: Vec::new(),
+        inputs: Vec::new(),
+        score: 0,
+        completion_time: Duration::ZERO,
+    }
+}
+
+/// Append this frame's time step and actions to the recording
+fn record_frame(mut recorder: ResMut<ReplayRecorder>, player_input: Res<PlayerInput>, time: Res<Time>) {
+    let Some(recording) = &mut recorder.recording else {
+        return;
+    };
+
+    let frame = recording.frame_times.len() as u32;
+    recording.frame_times.push(time.delta());
+
+    for pointer in &player_input.pointers {
+        recording.inputs.push(RecordedInput {
+            frame,
+            action: ReplayAction::Pointer {
+                id: pointer.id,
+                position: pointer.position.map(|position| (position.x, position.y)),
+                just_pressed: pointer.just_pressed,
+                just_released: pointer.just_released,
+            },
+        });
+    }
+    if player_input.toggle_slash_mode {
+        recording.inputs.push(RecordedInput {
+            frame,
+            action: ReplayAction::ToggleSlashMode,
+        });
+    }
+    if let Some(position) = player_input.use_powerup {
+        recording.inputs.push(RecordedInput {
+            frame,
+            action: ReplayAction::UsePowerup {
+                position: (position.x, position.y),
+            },
+        });
+    }
+    if let Some(powerup) = player_input.select_powerup {
+        recording.inputs.push(RecordedInput {
+            frame,
+            action: ReplayAction::SelectPowerup { powerup },
+        });
+    }
+}
+
+/// Save the recording once the level is completed, or drop it if the level was failed
+fn finish_recording(
+    mut recorder: ResMut<ReplayRecorder>,
+    mut level_complete_events: EventReader<LevelCompleteEvent>,
+    mut level_failed_events: EventReader<LevelFailedEvent>,
+    game_rng: Res<GameRng>,
+    play_area: Res<PlayArea>,
+) {
+    // Quits are followed by leaving the level or starting another, which already replace the recording
+    let timed_out = level_failed_events
+        .read()
+        .filter(|event| matches!(event.reason, FailureReason::TimeOut))
+        .count()
+        > 0;
+    if timed_out {
+        recorder.recording = None;
+    }
+
+    for event in level_complete_events.read() {
+        let Some(mut recording) = recorder.recording.take() else {
+            continue;
+        };
+
+        recording.seed = game_rng.seed();
+        recording.score = event.final_score;
+        recording.completion_time = event.completion_time;
+        recording.window_size = (play_area.size.x, play_area.size.y);
+
+        save_replay(&recording);
+    }
+}
+
+/// Stop recording when leaving the level without finishing it
+fn discard_recording(mut recorder: ResMut<ReplayRecorder>) {
+    recorder.recording = None;
+}
+
+/// Write a replay to `save/replays/level_<id>.ron`, replacing the previous run of that level
+fn save_replay(replay: &Replay) {
+    if cfg!(target_arch = "wasm32") {
+        // No filesystem on the web build
+        return;
+    }
+
+    let path = Path::new(REPLAY_DIR).join(format!("level_{}.ron", replay.level_id));
+    let config = ron::ser::PrettyConfig::default().compact_arrays(true);
+    let result = ron::ser::to_string_pretty(replay, config)
+        .map_err(std::io::Error::other)
+        .and_then(|contents| crate::save::write_file_atomic(&path, &contents));
+
+    match result {
+        Ok(()) => info!("Replay saved to {}", path.display()),
+        Err(err) => warn!("Failed to save replay to {}: {}", path.display(), err),
+    }
+}
+
+/// Jump straight into the replayed level at the recorded window size
+fn start_replay(
+    playback: Res<ReplayPlayback>,
+    mut level_data: ResMut<LevelData>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut windows: Query<&mut Window>,
+) {
+    let replay = &playback.replay;
+
+    // Replays may be of levels this save hasn't unlocked, so skip the unlock check
+    level_data.current_level = replay.level_id;
+    next_state.set(GameState::Playing);
+
+    if let Ok(mut window) = windows.single_mut() {
+        window.resolution.set(replay.window_size.0, replay.window_size.1);
+    }
+
+    info!(
+        "Replaying level {} (seed {}, {} frames, expecting score {} in {:.2}s)",
+        replay.level_id,
+        replay.seed,
+        replay.frame_times.len(),
+        replay.score,
+        replay.completion_time.as_secs_f32()
+    );
+}
+
+/// Restore the attack mode, powerup charges and upgrades the run started with, holding back the player's own
+fn apply_replay_start(mut playback: ResMut<ReplayPlayback>, mut game_data: ResMut<GameData>, mut level_data: ResMut<LevelData>) {
+    game_data.slash_mode = playback.replay.slash_mode;
+    let inventory = std::mem::replace(&mut level_data.powerup_inventory, playback.replay.powerup_inventory.clone());
+    playback.player_inventory = Some(inventory);
+    let upgrades = std::mem::replace(&mut level_data.upgrades, playback.replay.upgrades.clone());
+    playback.player_upgrades = Some(upgrades);
+}
+
+/// Replace live input with the recorded actions for the upcoming frame
+fn feed_replay_input(playback: Res<ReplayPlayback>, mut player_input: ResMut<PlayerInput>) {
+    *player_input = PlayerInput::default();
+
+    let frame = playback.frame as u32;
+    for input in playback.replay.inputs.iter().filter(|input| input.frame == frame) {
+        match input.action {
+            ReplayAction::Pointer {
+                id,
+                position,
+                just_pressed,
+                just_released,
+            } => player_input.pointers.push(Pointer {
+                id,
+                position: position.map(|(x, y)| Vec2::new(x, y)),
+                just_pressed,
+                just_released,
+            }),
+            ReplayAction::ToggleSlashMode => player_input.toggle_slash_mode = true,
+            ReplayAction::UsePowerup { position: (x, y) } => player_input.use_powerup = Some(Vec2::new(x, y)),
+            ReplayAction::SelectPowerup { powerup } => player_input.select_powerup = Some(powerup),
+        }
+    }
+}
+
+/// Move to the next recorded frame and queue its time step
+fn advance_replay(mut playback: ResMut<ReplayPlayback>, mut time_strategy: ResMut<TimeUpdateStrategy>, mut level_data: ResMut<LevelData>) {
+    if playback.finished {
+        return;
+    }
+
+    playback.frame += 1;
+
+    match playback.replay.frame_times.get(playback.frame) {
+        Some(frame_time) => *time_strategy = TimeUpdateStrategy::ManualDuration(*frame_time),
+        None => {
+            warn!("Replay ran out of frames before the level was completed; the run has desynced");
+            playback.finish(&mut level_data);
+            *time_strategy = TimeUpdateStrategy::Automatic;
+        }
+    }
+}
+
+/// Compare the replayed result with the recorded one
+fn check_replay_result(
+    mut playback: ResMut<ReplayPlayback>,
+    mut time_strategy: ResMut<TimeUpdateStrategy>,
+    mut level_data: ResMut<LevelData>,
+    mut level_complete_events: EventReader<LevelCompleteEvent>,
+    mut level_failed_events: EventReader<LevelFailedEvent>,
+) {
+    for event in level_failed_events.read() {
+        warn!("Replayed level {} failed ({:?}); the run has desynced", event.level_id, event.reason);
+        playback.finished = true;
+    }
+
+    for event in level_complete_events.read() {
+        let replay = &playback.replay;
+        if event.final_score == replay.score && event.completion_time == replay.completion_time {
+            info!("Replay verified: score {} in {:.2}s", event.final_score, event.completion_time.as_secs_f32());
+        } else {
+            warn!(
+                "Replay desynced: got score {} in {:.2}s, recorded score {} in {:.2}s",
+                event.final_score,
+                event.completion_time.as_secs_f32(),
+                replay.score,
+                replay.completion_time.as_secs_f32()
+            );
+        }
+        playback.finished = true;
+    }
+
+    if playback.finished {
+        playback.finish(&mut level_data);
+        *time_strategy = TimeUpdateStrategy::Automatic;
+    }
+}
+
+/// Stop playing back when leaving the level before the run is over
+fn stop_replay(mut playback: ResMut<ReplayPlayback>, mut time_strategy: ResMut<TimeUpdateStrategy>, mut level_data: ResMut<LevelData>) {
+    info!("Replay stopped before the run was over");
+    playback.finish(&mut level_data);
+    *time_strategy = TimeUpdateStrategy::Automatic;
+}
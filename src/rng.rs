@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+use crate::GameState;
+use crate::levels::{LevelData, LevelStartEvent};
+
+/// Plugin providing a seeded random number generator for all gameplay randomness
+pub struct RngPlugin {
+    /// Seed forced from the command line (`--seed <N>`), overriding level seeds
+    pub seed: Option<u64>,
+}
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameRng::new(self.seed))
+            .add_systems(OnEnter(GameState::Playing), seed_current_level)
+            // PreUpdate so the new seed is in place before any gameplay system draws from it
+            .add_systems(PreUpdate, reseed_on_level_start.run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// Random number generator reseeded at the start of every level so runs can be reproduced
+#[derive(Resource)]
+pub struct GameRng {
+    rng: StdRng,
+    seed: u64,
+    fixed_seed: Option<u64>,
+}
+
+impl GameRng {
+    pub fn new(fixed_seed: Option<u64>) -> Self {
+        let seed = fixed_seed.unwrap_or_else(rand::random);
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            seed,
+            fixed_seed,
+        }
+    }
+
+    /// Seed the current run was started with
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Reseed for a new level: command line seed first, then the level's own seed, otherwise a fresh random one
+    pub fn start_level(&mut self, level_seed: Option<u64>) {
+        self.seed = self.fixed_seed.or(level_seed).unwrap_or_else(rand::random);
+        self.rng = StdRng::seed_from_u64(self.seed);
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+/// Parse `--seed <N>` from the command line arguments
+pub fn parse_seed_arg(args: &[String]) -> Result<Option<u64>, String> {
+    let Some(position) = args.iter().position(|arg| arg == "--seed") else {
+        return Ok(None);
+    };
+
+    let value = args.get(position + 1).ok_or("--seed needs a value")?;
+    value
+        .parse()
+        .map(Some)
+        .map_err(|_| format!("invalid seed `{}`, expected an unsigned integer", value))
+}
+
+/// Seed the level the game starts on when entering from the main menu
+fn seed_current_level(mut game_rng: ResMut<GameRng>, level_data: Res<LevelData>) {
+    let level_seed = level_data.get_current_level().and_then(|level| level.seed);
+    game_rng.start_level(level_seed);
+    info!("Level {} seed: {}", level_data.current_level, game_rng.seed());
+}
+
+/// Reseed whenever a level is started or retried
+fn reseed_on_level_start(mut level_start_events: EventReader<LevelStartEvent>, mut game_rng: ResMut<GameRng>, level_data: Res<LevelData>) {
+    for event in level_start_events.read() {
+        let level_seed = level_data.get_level(event.level_id).and_then(|level| level.seed);
+        game_rng.start_level(level_seed);
+        info!("Level {} seed: {}", event.level_id, game_rng.seed());
+    }
+}
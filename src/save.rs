@@ -0,0 +1,162 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use crate::levels::{LevelCompleteEvent, LevelData, LevelProgress};
+use crate::powerups::{PowerupInventory, PowerupSpentEvent, PowerupUpgrades};
+use crate::replay::is_replaying;
+
+/// Location of the progress save file, relative to the working directory
+const SAVE_FILE_PATH: &str = "save/progress.ron";
+
+/// Bump whenever the layout of `SaveFile` changes in a non-compatible way
+const SAVE_VERSION: u32 = 1;
+
+/// Plugin that restores saved progress at startup and saves it whenever a level is completed or a powerup charge is spent
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        // PostUpdate so the completion has already been applied to `LevelData`.
+        // Replays play with the recorded charges and upgrades, which must never overwrite the player's own.
+        app.insert_resource(load_level_data()).add_systems(
+            PostUpdate,
+            save_on_level_complete
+                .run_if(on_event::<LevelCompleteEvent>.or(on_event::<PowerupSpentEvent>))
+                .run_if(not(is_replaying)),
+        );
+    }
+}
+
+/// On-disk representation of the player's progress
+#[derive(Serialize, Deserialize)]
+struct SaveFile {
+    version: u32,
+    current_level: u32,
+    level_progress: Vec<LevelProgress>,
+    /// Missing from saves made before powerups were stockpiled
+    #[serde(default)]
+    powerup_inventory: PowerupInventory,
+    /// Missing from saves made before powerups could be upgraded
+    #[serde(default)]
+    upgrades: PowerupUpgrades,
+}
+
+/// Build the level data for this session, restoring any saved progress.
+/// Falls back to `LevelData::default()` when the save is missing, corrupt or from another version.
+pub fn load_level_data() -> LevelData {
+    let mut level_data = LevelData::default();
+
+    let Some(save) = read_save_file(Path::new(SAVE_FILE_PATH)) else {
+        return level_data;
+    };
+
+    apply_save(&mut level_data, save);
+    info!("Loaded progress from {} ({} stars)", SAVE_FILE_PATH, level_data.get_total_stars());
+    level_data
+}
+
+/// Write current progress to disk, replacing the previous save atomically
+pub fn save_level_data(level_data: &LevelData) {
+    if cfg!(target_arch = "wasm32") {
+        // No filesystem on the web build
+        return;
+    }
+
+    let save = SaveFile {
+        version: SAVE_VERSION,
+        current_level: level_data.current_level,
+        level_progress: level_data.level_progress.clone(),
+        powerup_inventory: level_data.powerup_inventory.clone(),
+        upgrades: level_data.upgrades.clone(),
+    };
+
+    match write_save_file(Path::new(SAVE_FILE_PATH), &save) {
+        Ok(()) => debug!("Progress saved to {}", SAVE_FILE_PATH),
+        Err(err) => warn!("Failed to save progress to {}: {}", SAVE_FILE_PATH, err),
+    }
+}
+
+/// Persist progress after a level is completed
+fn save_on_level_complete(level_data: Res<LevelData>) {
+    save_level_data(&level_data);
+}
+
+/// Read and validate a save file, returning `None` if it can't be used
+fn read_save_file(path: &Path) -> Option<SaveFile> {
+    if cfg!(target_arch = "wasm32") {
+        return None;
+    }
+
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            debug!("No save file at {}, starting fresh", path.display());
+            return None;
+        }
+        Err(err) => {
+            warn!("Failed to read save file {}: {}", path.display(), err);
+            return None;
+        }
+    };
+
+    let save: SaveFile = match ron::from_str(&contents) {
+        Ok(save) => save,
+        Err(err) => {
+            warn!("Save file {} is corrupt, starting fresh: {}", path.display(), err);
+            return None;
+        }
+    };
+
+    if save.version != SAVE_VERSION {
+        warn!(
+            "Save file {} has unsupported version {} (expected {}), starting fresh",
+            path.display(),
+            save.version,
+            SAVE_VERSION
+        );
+        return None;
+    }
+
+    Some(save)
+}
+
+/// Serialize the save and write it atomically
+fn write_save_file(path: &Path, save: &SaveFile) -> std::io::Result<()> {
+    let contents = ron::ser::to_string_pretty(save, ron::ser::PrettyConfig::default()).map_err(std::io::Error::other)?;
+    write_file_atomic(path, &contents)
+}
+
+/// Write to a temporary file and rename it over the old one so a crash never leaves a partial file
+pub fn write_file_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let tmp_path = path.with_extension("ron.tmp");
+    {
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+    }
+    fs::rename(&tmp_path, path)
+}
+
+/// Copy saved progress onto freshly built level data
+fn apply_save(level_data: &mut LevelData, save: SaveFile) {
+    // Cover every level in the list, but keep progress beyond it: a level pack loaded later may have more levels
+    let mut level_progress = save.level_progress;
+    let progress_len = level_progress.len().max(level_data.levels.len());
+    level_progress.resize(progress_len, LevelProgress::default());
+    level_data.level_progress = level_progress;
+    level_data.powerup_inventory = save.powerup_inventory;
+    level_data.upgrades = save.upgrades;
+
+    if level_data.get_level(save.current_level).is_none() {
+        level_data.pending_current_level = Some(save.current_level);
+    } else if level_data.is_level_unlocked(save.current_level) {
+        level_data.current_level = save.current_level;
+    }
+}
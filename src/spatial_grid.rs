@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Side length of a grid cell in world units, about the diameter of the largest dandelion
+const CELL_SIZE: f32 = 100.0;
+
+/// Uniform grid bucketing entities by position, so proximity queries only visit nearby cells.
+/// Queries visit cells in a fixed order and entities in insertion order, keeping results deterministic.
+#[derive(Default)]
+pub struct SpatialGrid {
+    cells: HashMap<IVec2, Vec<(Entity, Vec2)>>,
+    /// Smallest and largest occupied cell, to clamp large queries
+    occupied: Option<IRect>,
+    len: usize,
+}
+
+impl SpatialGrid {
+    /// Remove every entity, keeping the allocated cells for the next rebuild
+    pub fn clear(&mut self) {
+        for cell in self.cells.values_mut() {
+            cell.clear();
+        }
+        self.occupied = None;
+        self.len = 0;
+    }
+
+    pub fn insert(&mut self, entity: Entity, position: Vec2) {
+        let cell = Self::cell(position);
+        self.cells.entry(cell).or_default().push((entity, position));
+        self.occupied = Some(match self.occupied {
+            Some(occupied) => occupied.union_point(cell),
+            None => IRect::from_corners(cell, cell),
+        });
+        self.len += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// World-space area covered by occupied cells; every entity lies inside it
+    pub fn bounds(&self) -> Option<Rect> {
+        self.occupied
+            .map(|occupied| Rect::from_corners(occupied.min.as_vec2() * CELL_SIZE, (occupied.max + IVec2::ONE).as_vec2() * CELL_SIZE))
+    }
+
+    /// Entities in the cells overlapping `rect`, a superset of those inside it
+    pub fn query_rect(&self, rect: Rect) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        // Only visit occupied cells; `IRect::intersect` would collapse a miss onto a row instead of emptying it
+        let cells = self
+            .occupied
+            .map(|occupied| IRect {
+                min: Self::cell(rect.min).max(occupied.min),
+                max: Self::cell(rect.max).min(occupied.max),
+            })
+            .filter(|cells| cells.min.cmple(cells.max).all());
+
+        cells
+            .into_iter()
+            .flat_map(|cells| (cells.min.y..=cells.max.y).flat_map(move |y| (cells.min.x..=cells.max.x).map(move |x| IVec2::new(x, y))))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+    }
+
+    /// Entities within `radius` of `center`
+    pub fn query_radius(&self, center: Vec2, radius: f32) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        let radius_squared = radius * radius;
+        self.query_rect(Rect::from_center_half_size(center, Vec2::splat(radius)))
+            .filter(move |(_, position)| position.distance_squared(center) <= radius_squared)
+    }
+
+    fn cell(position: Vec2) -> IVec2 {
+        (position / CELL_SIZE).floor().as_ivec2()
+    }
+}
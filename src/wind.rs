@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::GameState;
+use crate::levels::{LevelData, LevelStartEvent};
+use crate::pause_menu::PauseState;
+use crate::rng::GameRng;
+
+/// Seconds between shifts in the wind's heading
+const WIND_SHIFT_INTERVAL: f32 = 8.0;
+/// Widest swing, in radians, of a single shift in heading
+const WIND_MAX_SHIFT: f32 = std::f32::consts::FRAC_PI_2;
+/// How fast, in radians per second, the wind turns towards its new heading
+const WIND_TURN_RATE: f32 = 0.5;
+/// Speed in pixels per second that a full strength wind carries seeds
+const WIND_MAX_SPEED: f32 = 200.0;
+
+/// Plugin for the wind that carries seeds across the lawn
+pub struct WindPlugin;
+
+impl Plugin for WindPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Wind>()
+            .add_systems(OnEnter(GameState::Playing), setup_wind)
+            .add_systems(PreUpdate, setup_wind.run_if(in_state(GameState::Playing)).run_if(on_event::<LevelStartEvent>))
+            .add_systems(
+                Update,
+                (shift_wind, update_wind_display)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(in_state(PauseState::Playing)),
+            );
+    }
+}
+
+/// Wind blowing across the lawn; its strength is set per level and its heading wanders over time
+#[derive(Resource)]
+pub struct Wind {
+    /// Unit vector the wind blows towards
+    pub direction: Vec2,
+    /// From 0 for still air to 1 for a full gale
+    pub strength: f32,
+    /// Heading the wind is turning towards
+    heading: Vec2,
+    shift_timer: Timer,
+}
+
+impl Default for Wind {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl Wind {
+    /// Wind of `strength` that starts out blowing east
+    pub fn new(strength: f32) -> Self {
+        Self {
+            direction: Vec2::X,
+            strength,
+            heading: Vec2::X,
+            shift_timer: Timer::from_seconds(WIND_SHIFT_INTERVAL, TimerMode::Repeating),
+        }
+    }
+
+    /// Velocity the wind adds to anything it carries
+    pub fn velocity(&self) -> Vec2 {
+        self.direction * self.strength * WIND_MAX_SPEED
+    }
+
+    /// Compass point the wind blows towards, with north at the top of the screen
+    pub fn compass_point(&self) -> &'static str {
+        const POINTS: [&str; 8] = ["E", "NE", "N", "NW", "W", "SW", "S", "SE"];
+        let eighth = (self.direction.to_angle() / std::f32::consts::FRAC_PI_4).round() as i32;
+        POINTS[eighth.rem_euclid(8) as usize]
+    }
+}
+
+/// HUD text showing where the wind blows and how hard
+#[derive(Component)]
+pub struct WindText;
+
+/// Reset the wind to the current level's strength
+fn setup_wind(mut commands: Commands, level_data: Option<Res<LevelData>>) {
+    let strength = level_data
+        .as_ref()
+        .and_then(|level_data| level_data.get_current_level())
+        .map_or(0.0, |level| level.enemy_scaling.wind_strength);
+    commands.insert_resource(Wind::new(strength));
+}
+
+/// Pick a new heading now and then, and turn the wind towards it
+fn shift_wind(mut wind: ResMut<Wind>, time: Res<Time>, mut rng: ResMut<GameRng>) {
+    // Still air has nowhere to blow, and leaves the random sequence alone
+    if wind.strength <= 0.0 {
+        return;
+    }
+
+    wind.shift_timer.tick(time.delta());
+    if wind.shift_timer.just_finished() {
+        let swing = rng.gen_range(-WIND_MAX_SHIFT..WIND_MAX_SHIFT);
+        wind.heading = Vec2::from_angle(swing).rotate(wind.heading);
+        debug!("Wind shifting towards {:.0} degrees", wind.heading.to_angle().to_degrees());
+    }
+
+    let remaining = wind.direction.angle_to(wind.heading);
+    let turn = remaining.clamp(-WIND_TURN_RATE * time.delta_secs(), WIND_TURN_RATE * time.delta_secs());
+    wind.direction = Vec2::from_angle(turn).rotate(wind.direction);
+}
+
+/// Update the wind display
+fn update_wind_display(wind: Res<Wind>, mut wind_text_query: Query<&mut Text, With<WindText>>) {
+    if let Ok(mut text) = wind_text_query.single_mut() {
+        **text = if wind.strength > 0.0 {
+            format!("Wind: {} {:.0}%", wind.compass_point(), wind.strength * 100.0)
+        } else {
+            "Wind: calm".to_string()
+        };
+    }
+}
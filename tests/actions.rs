@@ -0,0 +1,230 @@
+//! Bound actions turned into gameplay input, driven by synthetic keyboard, mouse and gamepad events.
+
+use bevy::input::ButtonState;
+use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent, RawGamepadAxisChangedEvent, RawGamepadButtonChangedEvent, RawGamepadEvent};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::mouse::MouseButtonInput;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_enhanced_input::prelude::Input;
+
+use kill_all_dandelions::GameState;
+use kill_all_dandelions::actions::{ActionBindings, ActionsPlugin};
+use kill_all_dandelions::enemies::{Dandelion, DandelionSize, EnemyEntity, Species};
+use kill_all_dandelions::headless::HeadlessGameplayPlugin;
+use kill_all_dandelions::input::{GamepadReticle, InputPlugin, PlayerInput, PointerId};
+use kill_all_dandelions::pause_menu::PauseState;
+use kill_all_dandelions::play_area::PlayArea;
+use kill_all_dandelions::playing::GameData;
+
+/// App with the given screen active and bindings in place, after a frame to activate its input context
+fn actions_app(pause_state: PauseState, bindings: ActionBindings) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+        .insert_state(GameState::Playing)
+        .insert_state(pause_state)
+        .add_plugins((ActionsPlugin, InputPlugin))
+        .init_resource::<PlayArea>()
+        // Don't pick up bindings saved on this machine
+        .insert_resource(bindings);
+    app.finish();
+    app.cleanup();
+    app.update();
+    app
+}
+
+fn key(app: &mut App, key_code: KeyCode, state: ButtonState) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(bevy::input::keyboard::NativeKey::Unidentified),
+        state,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+fn mouse(app: &mut App, button: MouseButton, state: ButtonState) {
+    app.world_mut().send_event(MouseButtonInput {
+        button,
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+fn player_input(app: &mut App) -> PlayerInput {
+    app.update();
+    app.world().resource::<PlayerInput>().clone()
+}
+
+#[test]
+fn toggle_mode_follows_its_binding() {
+    let bindings = ActionBindings {
+        toggle_mode: vec![KeyCode::KeyT.into()],
+        ..default()
+    };
+    let mut app = actions_app(PauseState::Playing, bindings);
+
+    key(&mut app, KeyCode::Tab, ButtonState::Pressed);
+    assert!(!player_input(&mut app).toggle_slash_mode, "the default binding was replaced");
+
+    key(&mut app, KeyCode::KeyT, ButtonState::Pressed);
+    assert!(player_input(&mut app).toggle_slash_mode);
+
+    // Holding the key toggles only once
+    assert!(!player_input(&mut app).toggle_slash_mode);
+}
+
+#[test]
+fn attack_presses_and_releases_the_mouse_pointer() {
+    let mut app = actions_app(PauseState::Playing, ActionBindings::default());
+
+    mouse(&mut app, MouseButton::Left, ButtonState::Pressed);
+    let pointers = player_input(&mut app).pointers;
+    assert_eq!(pointers.len(), 1);
+    assert_eq!(pointers[0].id, PointerId::Mouse);
+    assert!(pointers[0].just_pressed && !pointers[0].just_released);
+
+    let pointers = player_input(&mut app).pointers;
+    assert!(pointers.len() == 1 && !pointers[0].just_pressed && !pointers[0].just_released);
+
+    mouse(&mut app, MouseButton::Left, ButtonState::Released);
+    let pointers = player_input(&mut app).pointers;
+    assert!(pointers.len() == 1 && pointers[0].just_released);
+
+    assert!(player_input(&mut app).pointers.is_empty());
+}
+
+#[test]
+fn gameplay_actions_are_inactive_while_paused() {
+    let mut app = actions_app(PauseState::Paused, ActionBindings::default());
+
+    key(&mut app, KeyCode::Tab, ButtonState::Pressed);
+    mouse(&mut app, MouseButton::Left, ButtonState::Pressed);
+    let input = player_input(&mut app);
+
+    assert!(!input.toggle_slash_mode);
+    assert!(input.pointers.is_empty());
+}
+
+#[test]
+fn bindings_survive_a_save_round_trip() {
+    let bindings = ActionBindings {
+        attack: vec![MouseButton::Right.into()],
+        pause: vec![Input::from(KeyCode::KeyP)],
+        ..default()
+    };
+
+    let saved = ron::ser::to_string_pretty(&bindings, ron::ser::PrettyConfig::default()).unwrap();
+    let loaded: ActionBindings = ron::from_str(&saved).unwrap();
+
+    assert_eq!(loaded, bindings);
+}
+
+/// Headless gameplay app with the given bindings, read from a connected gamepad, returning the gamepad entity
+fn gamepad_app(bindings: ActionBindings) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, HeadlessGameplayPlugin { seed: Some(1) }))
+        .add_plugins((ActionsPlugin, InputPlugin))
+        .insert_resource(bindings)
+        .insert_resource(GameData {
+            slash_mode: false,
+            ..GameData::new()
+        });
+    app.finish();
+    app.cleanup();
+
+    let gamepad = app.world_mut().spawn_empty().id();
+    // The platform backend sends connections straight to the system that adds the `Gamepad` component
+    app.world_mut().send_event(GamepadConnectionEvent::new(
+        gamepad,
+        GamepadConnection::Connected {
+            name: "Test pad".to_string(),
+            vendor_id: None,
+            product_id: None,
+        },
+    ));
+    app.update();
+    (app, gamepad)
+}
+
+/// Queue a gamepad event as the platform backend would send it, for the next frame
+fn gamepad_event(app: &mut App, event: RawGamepadEvent) {
+    app.world_mut().send_event(event);
+}
+
+fn stick(app: &mut App, gamepad: Entity, axis: GamepadAxis, value: f32) {
+    gamepad_event(app, RawGamepadEvent::Axis(RawGamepadAxisChangedEvent::new(gamepad, axis, value)));
+}
+
+fn button(app: &mut App, gamepad: Entity, button: GamepadButton, value: f32) {
+    gamepad_event(app, RawGamepadEvent::Button(RawGamepadButtonChangedEvent::new(gamepad, button, value)));
+}
+
+#[test]
+fn stick_moves_the_reticle_and_keeps_it_on_the_grass() {
+    let (mut app, gamepad) = gamepad_app(ActionBindings::default());
+    let start = app.world().resource::<GamepadReticle>().position;
+
+    stick(&mut app, gamepad, GamepadAxis::LeftStickX, 1.0);
+    for _ in 0..10 {
+        app.update();
+    }
+    let reticle = app.world().resource::<GamepadReticle>();
+    assert!(reticle.position.x > start.x + 50.0, "reticle moved right: {}", reticle.position);
+    assert!(reticle.visible);
+
+    for _ in 0..300 {
+        app.update();
+    }
+    let bounds = app.world().resource::<PlayArea>().grass_bounds(0.0);
+    assert_eq!(app.world().resource::<GamepadReticle>().position.x, bounds.max.x);
+}
+
+#[test]
+fn trigger_clicks_at_the_reticle() {
+    let (mut app, gamepad) = gamepad_app(ActionBindings::default());
+    let position = app.world().resource::<GamepadReticle>().position;
+    let dandelion = app
+        .world_mut()
+        .spawn((
+            Transform::from_translation(position.extend(10.0)),
+            Dandelion {
+                health: 1,
+                size: DandelionSize::Tiny,
+                species: Species::Dandelion,
+            },
+            EnemyEntity,
+        ))
+        .id();
+
+    button(&mut app, gamepad, GamepadButton::RightTrigger2, 1.0);
+    let pointers = player_input(&mut app).pointers;
+
+    assert_eq!(pointers.len(), 1);
+    assert_eq!(pointers[0].id, PointerId::Gamepad);
+    assert_eq!(pointers[0].position, Some(position));
+    assert!(pointers[0].just_pressed);
+    app.update();
+    assert!(app.world().get_entity(dandelion).is_err(), "the clicked dandelion died");
+}
+
+#[test]
+fn face_button_toggles_attack_mode() {
+    let (mut app, gamepad) = gamepad_app(ActionBindings::default());
+
+    button(&mut app, gamepad, GamepadButton::West, 1.0);
+    assert!(player_input(&mut app).toggle_slash_mode);
+}
+
+#[test]
+fn rebinding_a_key_keeps_the_face_buttons() {
+    let bindings = ActionBindings {
+        toggle_mode: vec![KeyCode::KeyT.into()],
+        ..default()
+    };
+    let (mut app, gamepad) = gamepad_app(bindings);
+
+    button(&mut app, gamepad, GamepadButton::West, 1.0);
+    assert!(player_input(&mut app).toggle_slash_mode, "face buttons are not rebindable");
+}
@@ -0,0 +1,950 @@
+//! Gameplay rules exercised headlessly: the gameplay plugins run under `MinimalPlugins`
+//! and clicks are injected through `PlayerInput`.
+
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use kill_all_dandelions::combos::PowerupTraces;
+use kill_all_dandelions::enemies::{
+    CloverRunners, Dandelion, DandelionAreaTracker, DandelionDeathEvent, DandelionMergeEvent, DandelionSize, DandelionUpgradeEvent, EnemyEntity, Flower,
+    HealthBar, MovingDandelion, Puffball, Root, SeedOrb, Species,
+};
+use kill_all_dandelions::headless::HeadlessGameplayPlugin;
+use kill_all_dandelions::input::{PlayerInput, Pointer, PointerId};
+use kill_all_dandelions::levels::{LevelData, LevelSession};
+use kill_all_dandelions::playing::GameData;
+use kill_all_dandelions::powerups::{
+    FireIgnition, FireManager, Goose, LawnMower, PoisonZone, Powerup, PowerupInventory, PowerupType, PowerupUpgrades, Rabbit, Upgrade,
+};
+use kill_all_dandelions::wind::Wind;
+
+/// Events seen by the observers registered in `test_app`
+#[derive(Resource, Default)]
+struct SeenEvents {
+    deaths: Vec<DandelionSize>,
+    merges: Vec<(DandelionSize, DandelionSize)>,
+    upgrades: Vec<(DandelionSize, DandelionSize)>,
+}
+
+/// Headless app with the gameplay plugins, already in a level after the first update
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, HeadlessGameplayPlugin { seed: Some(1) }))
+        .insert_resource(GameData {
+            // Single target clicks, so each click hits exactly one dandelion
+            slash_mode: false,
+            ..GameData::new()
+        })
+        .init_resource::<SeenEvents>()
+        .add_observer(|trigger: Trigger<DandelionDeathEvent>, mut seen: ResMut<SeenEvents>| seen.deaths.push(trigger.event().size))
+        .add_observer(|trigger: Trigger<DandelionMergeEvent>, mut seen: ResMut<SeenEvents>| {
+            seen.merges.push((trigger.event().old_size, trigger.event().new_size))
+        })
+        .add_observer(|trigger: Trigger<DandelionUpgradeEvent>, mut seen: ResMut<SeenEvents>| {
+            seen.upgrades.push((trigger.event().old_size, trigger.event().new_size))
+        });
+
+    app.finish();
+    app.cleanup();
+    // Enter the playing state so the level resources are set up
+    app.update();
+    app
+}
+
+fn spawn_dandelion(app: &mut App, size: DandelionSize, position: Vec2) -> Entity {
+    app.world_mut()
+        .spawn((
+            Transform::from_translation(position.extend(10.0)),
+            Dandelion {
+                health: size.base_health(),
+                size,
+                species: Species::Dandelion,
+            },
+            EnemyEntity,
+        ))
+        .id()
+}
+
+/// Touch down and lift each finger at its position, all on the same frame
+fn tap(app: &mut App, positions: &[Vec2]) {
+    app.world_mut().resource_mut::<PlayerInput>().pointers = positions
+        .iter()
+        .enumerate()
+        .map(|(finger, &position)| Pointer {
+            id: PointerId::Touch(finger as u64),
+            position: Some(position),
+            just_pressed: true,
+            just_released: true,
+        })
+        .collect();
+    app.update();
+    app.world_mut().resource_mut::<PlayerInput>().pointers.clear();
+}
+
+/// Press at `position` for one frame
+fn click(app: &mut App, position: Vec2) {
+    tap(app, &[position]);
+}
+
+/// Swipe one finger through each trail at once in slash mode, one point per frame, releasing on the last
+fn swipe(app: &mut App, trails: &[&[Vec2]]) {
+    app.world_mut().resource_mut::<GameData>().slash_mode = true;
+    let frames = trails.iter().map(|trail| trail.len()).max().unwrap_or(0);
+    for frame in 0..frames {
+        app.world_mut().resource_mut::<PlayerInput>().pointers = trails
+            .iter()
+            .enumerate()
+            .filter(|(_, trail)| frame < trail.len())
+            .map(|(finger, trail)| Pointer {
+                id: PointerId::Touch(finger as u64),
+                position: Some(trail[frame]),
+                just_pressed: frame == 0,
+                just_released: frame == trail.len() - 1,
+            })
+            .collect();
+        app.update();
+    }
+    app.world_mut().resource_mut::<PlayerInput>().pointers.clear();
+}
+
+fn run_frames(app: &mut App, frames: u32) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+fn count<F: bevy::ecs::query::QueryFilter>(app: &mut App) -> usize {
+    app.world_mut().query_filtered::<(), F>().iter(app.world()).count()
+}
+
+fn dandelion_size(app: &App, entity: Entity) -> Option<DandelionSize> {
+    app.world().get::<Dandelion>(entity).map(|dandelion| dandelion.size)
+}
+
+#[test]
+fn killing_a_tiny_dandelion_scatters_its_seeds() {
+    let mut app = test_app();
+    let dandelion = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+
+    click(&mut app, Vec2::ZERO);
+
+    assert!(app.world().get_entity(dandelion).is_err(), "the clicked dandelion should be despawned");
+    assert_eq!(app.world().resource::<SeenEvents>().deaths, vec![DandelionSize::Tiny]);
+    assert_eq!(count::<With<SeedOrb>>(&mut app), DandelionSize::Tiny.spawn_count() as usize);
+}
+
+#[test]
+fn same_size_dandelions_within_merge_radius_merge() {
+    let mut app = test_app();
+    let offset = DandelionSize::Small.merge_radius() * 0.9;
+    let first = spawn_dandelion(&mut app, DandelionSize::Small, Vec2::ZERO);
+    let second = spawn_dandelion(&mut app, DandelionSize::Small, Vec2::new(offset, 0.0));
+    // Far enough from the pair that it must stay put
+    let loner = spawn_dandelion(&mut app, DandelionSize::Small, Vec2::new(300.0, 0.0));
+
+    app.update();
+
+    assert_eq!(app.world().resource::<SeenEvents>().merges, vec![(DandelionSize::Small, DandelionSize::Medium)]);
+    assert!(app.world().get_entity(first).is_err());
+    assert!(app.world().get_entity(second).is_err());
+    assert_eq!(dandelion_size(&app, loner), Some(DandelionSize::Small));
+
+    let merged: Vec<Vec2> = app
+        .world_mut()
+        .query::<(&Dandelion, &Transform)>()
+        .iter(app.world())
+        .filter(|(dandelion, _)| dandelion.size == DandelionSize::Medium)
+        .map(|(_, transform)| transform.translation.truncate())
+        .collect();
+    assert_eq!(merged, vec![Vec2::new(offset / 2.0, 0.0)], "one Medium should appear at the midpoint");
+}
+
+#[test]
+fn different_size_dandelions_do_not_merge() {
+    let mut app = test_app();
+    let tiny = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+    let small = spawn_dandelion(&mut app, DandelionSize::Small, Vec2::new(5.0, 0.0));
+
+    app.update();
+
+    assert!(app.world().resource::<SeenEvents>().merges.is_empty());
+    assert_eq!(dandelion_size(&app, tiny), Some(DandelionSize::Tiny));
+    assert_eq!(dandelion_size(&app, small), Some(DandelionSize::Small));
+}
+
+#[test]
+fn swipe_slashes_every_dandelion_along_its_trail() {
+    let mut app = test_app();
+    let on_trail = [Vec2::new(0.0, 0.0), Vec2::new(60.0, 0.0), Vec2::new(60.0, 60.0)].map(|position| spawn_dandelion(&mut app, DandelionSize::Tiny, position));
+    let off_trail = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(0.0, 80.0));
+
+    swipe(
+        &mut app,
+        &[&[Vec2::new(-20.0, 0.0), Vec2::new(30.0, 0.0), Vec2::new(60.0, 0.0), Vec2::new(60.0, 70.0)]],
+    );
+
+    for dandelion in on_trail {
+        assert!(app.world().get_entity(dandelion).is_err(), "dandelions on the trail should be slashed");
+    }
+    assert!(app.world().get_entity(off_trail).is_ok(), "dandelions off the trail should be untouched");
+    assert_eq!(app.world().resource::<SeenEvents>().deaths.len(), 3);
+}
+
+#[test]
+fn slash_trail_is_cut_off_at_its_length() {
+    let mut app = test_app();
+    app.world_mut().resource_mut::<GameData>().slash_length = 100.0;
+    let near = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(50.0, 0.0));
+    let far = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(200.0, 0.0));
+
+    swipe(&mut app, &[&[Vec2::ZERO, Vec2::new(120.0, 0.0), Vec2::new(250.0, 0.0)]]);
+
+    assert!(app.world().get_entity(near).is_err(), "the start of the trail should slash");
+    assert!(app.world().get_entity(far).is_ok(), "the trail should stop before reaching the far dandelion");
+}
+
+#[test]
+fn every_touch_in_a_frame_hits() {
+    let mut app = test_app();
+    let positions = [Vec2::new(-200.0, 0.0), Vec2::new(0.0, 150.0), Vec2::new(200.0, 0.0)];
+    let dandelions = positions.map(|position| spawn_dandelion(&mut app, DandelionSize::Tiny, position));
+
+    tap(&mut app, &positions);
+
+    for dandelion in dandelions {
+        assert!(app.world().get_entity(dandelion).is_err(), "each touch should kill the dandelion under it");
+    }
+    assert_eq!(app.world().resource::<SeenEvents>().deaths.len(), 3);
+}
+
+#[test]
+fn touches_on_the_same_dandelion_kill_it_once() {
+    let mut app = test_app();
+    let dandelion = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+
+    tap(&mut app, &[Vec2::ZERO, Vec2::new(5.0, 0.0)]);
+
+    assert!(app.world().get_entity(dandelion).is_err());
+    assert_eq!(app.world().resource::<SeenEvents>().deaths, vec![DandelionSize::Tiny]);
+}
+
+#[test]
+fn each_finger_swipes_its_own_trail() {
+    let mut app = test_app();
+    let top = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(0.0, 100.0));
+    let bottom = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(0.0, -100.0));
+    // Between the two swipes, so only a single trail joining them would reach it
+    let middle = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+
+    swipe(
+        &mut app,
+        &[
+            &[Vec2::new(-50.0, 100.0), Vec2::new(0.0, 100.0), Vec2::new(50.0, 100.0)],
+            &[Vec2::new(50.0, -100.0), Vec2::new(0.0, -100.0), Vec2::new(-50.0, -100.0)],
+        ],
+    );
+
+    assert!(app.world().get_entity(top).is_err());
+    assert!(app.world().get_entity(bottom).is_err());
+    assert!(app.world().get_entity(middle).is_ok(), "the two swipes should not be joined into one trail");
+}
+
+#[test]
+fn huge_moving_dandelion_upgrades_its_neighbours() {
+    let mut app = test_app();
+    let mut rng = StdRng::seed_from_u64(0);
+    app.world_mut().spawn((
+        Transform::from_translation(Vec3::new(0.0, 0.0, 10.0)),
+        Dandelion {
+            health: DandelionSize::Huge.base_health(),
+            size: DandelionSize::Huge,
+            species: Species::Dandelion,
+        },
+        MovingDandelion::new(&mut rng),
+        EnemyEntity,
+    ));
+    let tiny = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(50.0, 0.0));
+    let medium = spawn_dandelion(&mut app, DandelionSize::Medium, Vec2::new(-50.0, 0.0));
+    let far = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(0.0, 250.0));
+
+    app.update();
+
+    assert_eq!(dandelion_size(&app, tiny), Some(DandelionSize::Small));
+    assert_eq!(dandelion_size(&app, medium), Some(DandelionSize::Large));
+    assert_eq!(dandelion_size(&app, far), Some(DandelionSize::Tiny));
+    assert_eq!(app.world().resource::<SeenEvents>().upgrades.len(), 2);
+
+    // The cooldown stops the same neighbours from being upgraded again straight away
+    app.update();
+    assert_eq!(dandelion_size(&app, tiny), Some(DandelionSize::Small));
+    assert_eq!(dandelion_size(&app, medium), Some(DandelionSize::Large));
+}
+
+#[test]
+fn fire_chain_stops_at_max_generation() {
+    let mut app = test_app();
+
+    // Line of dandelions close enough for each fire to reach only the next one
+    let spacing = 90.0;
+    let start = Vec2::new(-500.0, 0.0);
+    let chain_length = FireManager::MAX_GENERATION + 3;
+    let chain: Vec<Entity> = (1..=chain_length)
+        .map(|i| spawn_dandelion(&mut app, DandelionSize::Tiny, start + Vec2::new(spacing * i as f32, 0.0)))
+        .collect();
+
+    app.world_mut().spawn((
+        Transform::from_translation(start.extend(10.0)),
+        Powerup {
+            powerup_type: PowerupType::Flamethrower,
+        },
+    ));
+    click(&mut app, start);
+
+    let mut max_generation = 0;
+    for _ in 0..30 {
+        app.update();
+        let generations = app.world_mut().query::<&FireIgnition>().iter(app.world()).map(FireIgnition::generation).max();
+        max_generation = max_generation.max(generations.unwrap_or(0));
+    }
+
+    assert_eq!(max_generation, FireManager::MAX_GENERATION);
+
+    // Fires up to the last generation each burn one more dandelion, the last generation spreads no further
+    let burned = (FireManager::MAX_GENERATION + 1) as usize;
+    for (i, dandelion) in chain.iter().enumerate() {
+        let alive = app.world().get_entity(*dandelion).is_ok();
+        assert_eq!(alive, i >= burned, "dandelion {} of the chain", i + 1);
+    }
+}
+
+#[test]
+fn rabbit_reproduces_after_two_kills() {
+    let mut app = test_app();
+    app.world_mut().spawn((Transform::default(), Rabbit::default()));
+
+    let first = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(10.0, 0.0));
+    run_frames(&mut app, 5);
+    assert!(app.world().get_entity(first).is_err(), "the rabbit should eat the first dandelion");
+    assert_eq!(count::<With<Rabbit>>(&mut app), 1, "one kill is not enough to reproduce");
+
+    let second = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(-10.0, 0.0));
+    run_frames(&mut app, 5);
+    assert!(app.world().get_entity(second).is_err(), "the rabbit should eat the second dandelion");
+    assert_eq!(count::<With<Rabbit>>(&mut app), 3, "the rabbit should be replaced by three new ones");
+}
+
+#[test]
+fn stars_grant_powerup_charges() {
+    let mut level_data = LevelData::default();
+    let three_star = level_data.get_level(1).unwrap().time_limits.three_star;
+
+    let total_charges = |level_data: &LevelData| {
+        PowerupType::all()
+            .into_iter()
+            .map(|powerup_type| level_data.powerup_inventory.charges(powerup_type))
+            .sum::<u32>()
+    };
+
+    let two_star = level_data.get_level(1).unwrap().time_limits.two_star;
+    level_data.complete_level(1, two_star, 2000);
+    assert_eq!(total_charges(&level_data), 2);
+
+    // Completing the level again only pays for stars beyond the previous best
+    level_data.complete_level(1, two_star, 2000);
+    assert_eq!(total_charges(&level_data), 2);
+    // A faster run counts its stars even with a lower score
+    level_data.complete_level(1, three_star, 1000);
+    assert_eq!(total_charges(&level_data), 3);
+    level_data.complete_level(1, three_star, 1000);
+    assert_eq!(total_charges(&level_data), 3);
+    // Charges are spread over the powerup types rather than stacked on one
+    for powerup_type in PowerupType::all() {
+        assert!(
+            level_data.powerup_inventory.charges(powerup_type) <= 1,
+            "{:?} got more than its share",
+            powerup_type
+        );
+    }
+}
+
+#[test]
+fn shorter_level_list_keeps_progress_beyond_it() {
+    let mut level_data = LevelData::default();
+    let levels = level_data.levels.clone();
+    let three_star = levels[11].time_limits.three_star;
+    level_data.complete_level(12, three_star, 2000);
+
+    level_data.replace_levels(levels[..3].to_vec());
+    assert_eq!(level_data.level_progress.len(), 12, "progress on levels beyond the list should be kept");
+
+    level_data.replace_levels(levels);
+    assert!(level_data.get_level_progress(12).unwrap().completed);
+}
+
+#[test]
+fn selected_slot_deploys_on_the_next_lawn_tap() {
+    let mut app = test_app();
+    let mut inventory = PowerupInventory::default();
+    inventory.add(PowerupType::Bunny, 1);
+    app.world_mut().resource_mut::<LevelData>().powerup_inventory = inventory;
+
+    app.world_mut().resource_mut::<PlayerInput>().select_powerup = Some(PowerupType::Bunny);
+    app.update();
+    app.world_mut().resource_mut::<PlayerInput>().select_powerup = None;
+    assert_eq!(count::<With<Rabbit>>(&mut app), 0, "selecting a slot only arms it");
+
+    click(&mut app, Vec2::new(0.0, 50.0));
+    assert_eq!(count::<With<Rabbit>>(&mut app), 3);
+    assert_eq!(app.world().resource::<LevelData>().powerup_inventory.charges(PowerupType::Bunny), 0);
+
+    // The slot is disarmed after use
+    click(&mut app, Vec2::new(0.0, 50.0));
+    assert_eq!(count::<With<Rabbit>>(&mut app), 3);
+}
+
+#[test]
+fn empty_slot_cannot_be_armed() {
+    let mut app = test_app();
+
+    app.world_mut().resource_mut::<PlayerInput>().select_powerup = Some(PowerupType::Flamethrower);
+    app.update();
+    app.world_mut().resource_mut::<PlayerInput>().select_powerup = None;
+    click(&mut app, Vec2::new(0.0, 50.0));
+
+    assert_eq!(count::<With<FireIgnition>>(&mut app), 0);
+}
+
+#[test]
+fn poison_zone_wears_dandelions_down() {
+    let mut app = test_app();
+    let dandelion = spawn_dandelion(&mut app, DandelionSize::Medium, Vec2::new(20.0, 0.0));
+    app.world_mut().spawn((Transform::default(), Sprite::default(), PoisonZone::default()));
+
+    // One tick of damage is not enough for a medium dandelion
+    run_frames(&mut app, 40);
+    assert!(app.world().get_entity(dandelion).is_ok());
+
+    run_frames(&mut app, 60);
+    assert!(app.world().get_entity(dandelion).is_err(), "the poison should kill it");
+    assert_eq!(app.world().resource::<SeenEvents>().deaths, vec![DandelionSize::Medium]);
+}
+
+#[test]
+fn seeds_landing_in_poison_do_not_sprout() {
+    let mut app = test_app();
+    spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+
+    // A zone on the dandelion and a ring of zones around it cover everywhere its seeds can land
+    let zone_centers = std::iter::once(Vec2::ZERO).chain((0..6).map(|i| Vec2::from_angle(i as f32 * std::f32::consts::TAU / 6.0) * 110.0));
+    for center in zone_centers {
+        app.world_mut()
+            .spawn((Transform::from_translation(center.extend(11.0)), Sprite::default(), PoisonZone::default()));
+    }
+
+    click(&mut app, Vec2::ZERO);
+    assert_eq!(count::<With<SeedOrb>>(&mut app), DandelionSize::Tiny.spawn_count() as usize);
+
+    run_frames(&mut app, 60);
+    assert_eq!(count::<With<SeedOrb>>(&mut app), 0);
+    assert_eq!(count::<With<Dandelion>>(&mut app), 0, "no seed should have sprouted");
+}
+
+#[test]
+fn lawn_mower_cuts_its_lane_only() {
+    let mut app = test_app();
+    let start = Vec2::new(-300.0, 0.0);
+    let in_lane = [
+        spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(-200.0, 0.0)),
+        spawn_dandelion(&mut app, DandelionSize::Medium, Vec2::new(0.0, 30.0)),
+        spawn_dandelion(&mut app, DandelionSize::Small, Vec2::new(200.0, -30.0)),
+    ];
+    let beside_lane = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(0.0, 250.0));
+    let behind = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(-500.0, 0.0));
+
+    app.world_mut().spawn((
+        Transform::from_translation(start.extend(10.0)),
+        Powerup {
+            powerup_type: PowerupType::LawnMower,
+        },
+    ));
+    click(&mut app, start);
+    assert_eq!(count::<With<LawnMower>>(&mut app), 1);
+
+    // Far enough for the mower to cross the whole lawn
+    run_frames(&mut app, 200);
+
+    for dandelion in in_lane {
+        assert!(app.world().get_entity(dandelion).is_err(), "dandelions in the lane are cut");
+    }
+    assert!(app.world().get_entity(beside_lane).is_ok());
+    assert!(app.world().get_entity(behind).is_ok(), "the mower drives away from where it started");
+    assert_eq!(count::<With<LawnMower>>(&mut app), 0, "the mower leaves the lawn");
+}
+
+/// App with a lawn mower driving along the bottom of the lawn and a tiny dandelion's seeds in the air
+fn mower_and_seeds_app() -> App {
+    let mut app = test_app();
+    let start = Vec2::new(-300.0, -250.0);
+    app.world_mut().spawn((
+        Transform::from_translation(start.extend(10.0)),
+        Powerup {
+            powerup_type: PowerupType::LawnMower,
+        },
+    ));
+    click(&mut app, start);
+
+    spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+    click(&mut app, Vec2::ZERO);
+    app
+}
+
+#[test]
+fn lawn_mower_cuts_seeds_on_the_frame_they_land() {
+    // Find out when the seeds land, and how many sprout when the mower stays away
+    let mut app = mower_and_seeds_app();
+    let mut frames_in_air = 0;
+    while count::<With<SeedOrb>>(&mut app) > 0 {
+        app.update();
+        frames_in_air += 1;
+    }
+    let sprouted = count::<With<Dandelion>>(&mut app);
+    assert_eq!(sprouted, DandelionSize::Tiny.spawn_count() as usize);
+
+    // Drive the mower over one seed just as it lands
+    let mut app = mower_and_seeds_app();
+    run_frames(&mut app, frames_in_air - 1);
+    let seed = app
+        .world_mut()
+        .query_filtered::<&Transform, With<SeedOrb>>()
+        .iter(app.world())
+        .next()
+        .unwrap()
+        .translation;
+    let mut mowers = app.world_mut().query_filtered::<&mut Transform, With<LawnMower>>();
+    mowers.single_mut(app.world_mut()).unwrap().translation = (seed.truncate() - Vec2::new(3.0, 0.0)).extend(10.0);
+    app.update();
+
+    assert_eq!(count::<With<SeedOrb>>(&mut app), 0, "every seed should have landed");
+    assert!(count::<With<Dandelion>>(&mut app) < sprouted, "the seed under the mower should not sprout");
+}
+
+#[test]
+fn geese_catch_seeds_before_they_sprout() {
+    let mut app = test_app();
+    let start = Vec2::new(-100.0, 0.0);
+    app.world_mut().spawn((
+        Transform::from_translation(start.extend(10.0)),
+        Powerup {
+            powerup_type: PowerupType::Goose,
+        },
+    ));
+    click(&mut app, start);
+    assert_eq!(count::<With<Goose>>(&mut app), 2);
+
+    let dandelion = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+    click(&mut app, Vec2::ZERO);
+    assert!(app.world().get_entity(dandelion).is_err());
+    assert_eq!(count::<With<SeedOrb>>(&mut app), DandelionSize::Tiny.spawn_count() as usize);
+
+    run_frames(&mut app, 60);
+    assert_eq!(count::<With<SeedOrb>>(&mut app), 0);
+    assert_eq!(count::<With<Dandelion>>(&mut app), 0, "every seed was eaten before it landed");
+}
+
+#[test]
+fn upgrades_cost_stars_and_stop_at_max_level() {
+    let mut level_data = LevelData::default();
+    assert!(!level_data.buy_upgrade(Upgrade::RabbitCount), "no stars to spend yet");
+
+    let three_star = level_data.get_level(1).unwrap().time_limits.three_star;
+    level_data.complete_level(1, three_star, 2000);
+    assert_eq!(level_data.unspent_stars(), 3);
+
+    // Levels cost 1, then 2 stars
+    assert!(level_data.buy_upgrade(Upgrade::RabbitCount));
+    assert!(level_data.buy_upgrade(Upgrade::RabbitCount));
+    assert_eq!(level_data.unspent_stars(), 0);
+    assert!(!level_data.buy_upgrade(Upgrade::FireRadius), "spent stars can't be spent again");
+    assert_eq!(level_data.upgrades.rabbit_count(), 5);
+
+    let mut upgrades = PowerupUpgrades::default();
+    assert!(upgrades.buy(Upgrade::RabbitAppetite, 10));
+    assert!(!upgrades.buy(Upgrade::RabbitAppetite, 10), "rabbits can't multiply any sooner");
+    assert_eq!(upgrades.rabbit_litter_after(), 1);
+}
+
+#[test]
+fn upgraded_rabbits_multiply_after_one_kill() {
+    let mut app = test_app();
+    let mut upgrades = PowerupUpgrades::default();
+    assert!(upgrades.buy(Upgrade::RabbitCount, 1));
+    assert!(upgrades.buy(Upgrade::RabbitAppetite, 2));
+    app.world_mut().resource_mut::<LevelData>().upgrades = upgrades;
+
+    app.world_mut().spawn((
+        Transform::default(),
+        Powerup {
+            powerup_type: PowerupType::Bunny,
+        },
+    ));
+    click(&mut app, Vec2::ZERO);
+    assert_eq!(count::<With<Rabbit>>(&mut app), 4, "one more rabbit per litter");
+
+    // Far enough away that only the nearest rabbit reaches it
+    let dandelion = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(150.0, 0.0));
+    for _ in 0..90 {
+        app.update();
+        if app.world().get_entity(dandelion).is_err() {
+            break;
+        }
+    }
+    assert!(app.world().get_entity(dandelion).is_err(), "a rabbit should eat the dandelion");
+    assert_eq!(count::<With<Rabbit>>(&mut app), 7, "the rabbit that ate should be replaced by a new litter");
+}
+
+#[test]
+fn rabbits_flee_fire() {
+    let mut app = test_app();
+    let rabbit = app.world_mut().spawn((Transform::default(), Rabbit::default())).id();
+    app.world_mut().spawn((
+        Transform::from_translation(Vec3::new(30.0, 0.0, 12.0)),
+        Sprite::default(),
+        FireIgnition::default(),
+    ));
+
+    run_frames(&mut app, 10);
+    let position = app.world().get::<Transform>(rabbit).unwrap().translation;
+    assert!(position.x < -10.0, "the rabbit should run away from the fire, got {position}");
+}
+
+#[test]
+fn fire_lit_where_a_rabbit_just_ate_spreads_further() {
+    let mut app = test_app();
+
+    let spacing = 90.0;
+    let start = Vec2::new(-500.0, 0.0);
+    let chain_length = FireManager::MAX_GENERATION + 3;
+    for i in 1..=chain_length {
+        spawn_dandelion(&mut app, DandelionSize::Tiny, start + Vec2::new(spacing * i as f32, 0.0));
+    }
+
+    app.world_mut().resource_mut::<PowerupTraces>().leave(PowerupType::Bunny, start);
+    app.world_mut().spawn((
+        Transform::from_translation(start.extend(10.0)),
+        Powerup {
+            powerup_type: PowerupType::Flamethrower,
+        },
+    ));
+    click(&mut app, start);
+
+    let mut max_generation = 0;
+    for _ in 0..30 {
+        app.update();
+        let generations = app.world_mut().query::<&FireIgnition>().iter(app.world()).map(FireIgnition::generation).max();
+        max_generation = max_generation.max(generations.unwrap_or(0));
+    }
+    assert_eq!(max_generation, FireManager::MAX_GENERATION + 1);
+}
+
+#[test]
+fn weed_killer_puts_out_fire() {
+    let mut app = test_app();
+    app.world_mut().spawn((Transform::default(), Sprite::default(), FireIgnition::default()));
+    let far_fire = app
+        .world_mut()
+        .spawn((
+            Transform::from_translation(Vec3::new(400.0, 0.0, 12.0)),
+            Sprite::default(),
+            FireIgnition::default(),
+        ))
+        .id();
+    app.world_mut().spawn((
+        Transform::from_translation(Vec3::new(20.0, 0.0, 11.0)),
+        Sprite::default(),
+        PoisonZone::default(),
+    ));
+
+    run_frames(&mut app, 3);
+    assert_eq!(count::<With<FireIgnition>>(&mut app), 1, "only the fire outside the spray should keep burning");
+    assert!(app.world().get_entity(far_fire).is_ok());
+}
+
+fn spawn_clover(app: &mut App, position: Vec2) -> Entity {
+    app.world_mut()
+        .spawn((
+            Transform::from_translation(position.extend(10.0)),
+            Dandelion {
+                health: 1,
+                size: DandelionSize::Tiny,
+                species: Species::Clover,
+            },
+            CloverRunners::default(),
+            EnemyEntity,
+        ))
+        .id()
+}
+
+fn clover_count(app: &mut App) -> usize {
+    app.world_mut()
+        .query::<&Dandelion>()
+        .iter(app.world())
+        .filter(|weed| weed.species == Species::Clover)
+        .count()
+}
+
+#[test]
+fn clover_spreads_by_runners_while_alive() {
+    let mut app = test_app();
+    spawn_clover(&mut app, Vec2::ZERO);
+
+    // Runners root every 4 seconds
+    run_frames(&mut app, 200);
+    assert_eq!(clover_count(&mut app), 1);
+    run_frames(&mut app, 60);
+    assert_eq!(clover_count(&mut app), 2, "the patch should have rooted a runner");
+    run_frames(&mut app, 240);
+    assert!(clover_count(&mut app) >= 3, "both patches keep spreading");
+}
+
+#[test]
+fn killing_clover_scatters_no_seeds_and_scores_more() {
+    let mut app = test_app();
+    let clover = spawn_clover(&mut app, Vec2::ZERO);
+
+    click(&mut app, Vec2::ZERO);
+
+    assert!(app.world().get_entity(clover).is_err());
+    assert_eq!(count::<With<SeedOrb>>(&mut app), 0, "clover doesn't spread when killed");
+    assert_eq!(app.world().resource::<GameData>().score, Species::Clover.points());
+    assert!(Species::Clover.points() > Species::Dandelion.points());
+}
+
+#[test]
+fn clover_does_not_merge() {
+    let mut app = test_app();
+    let first = spawn_clover(&mut app, Vec2::ZERO);
+    let second = spawn_clover(&mut app, Vec2::new(10.0, 0.0));
+
+    run_frames(&mut app, 3);
+    assert!(app.world().get_entity(first).is_ok() && app.world().get_entity(second).is_ok());
+    assert!(app.world().resource::<SeenEvents>().merges.is_empty());
+}
+
+fn spawn_thistle(app: &mut App, size: DandelionSize, health: u32, position: Vec2) -> Entity {
+    app.world_mut()
+        .spawn((
+            Transform::from_translation(position.extend(10.0)),
+            Dandelion {
+                health,
+                size,
+                species: Species::Thistle,
+            },
+            EnemyEntity,
+        ))
+        .id()
+}
+
+#[test]
+fn clicking_a_thistle_resets_the_combo() {
+    let mut app = test_app();
+    spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(-200.0, 0.0));
+    spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(200.0, 0.0));
+    let thistle = spawn_thistle(&mut app, DandelionSize::Small, 2, Vec2::ZERO);
+
+    click(&mut app, Vec2::new(-200.0, 0.0));
+    click(&mut app, Vec2::new(200.0, 0.0));
+    assert_eq!(app.world().resource::<GameData>().combo, 2);
+
+    click(&mut app, Vec2::ZERO);
+    assert_eq!(app.world().resource::<GameData>().combo, 0, "clicking a thistle should break the combo");
+    assert_eq!(
+        app.world().get::<Dandelion>(thistle).map(|weed| weed.health),
+        Some(1),
+        "the click still hurts it"
+    );
+}
+
+#[test]
+fn slashing_a_thistle_keeps_the_combo() {
+    let mut app = test_app();
+    spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(-200.0, 0.0));
+    let thistle = spawn_thistle(&mut app, DandelionSize::Small, 1, Vec2::ZERO);
+
+    click(&mut app, Vec2::new(-200.0, 0.0));
+    let seeds = count::<With<SeedOrb>>(&mut app);
+    swipe(&mut app, &[&[Vec2::new(-20.0, 0.0), Vec2::new(20.0, 0.0)]]);
+
+    assert!(app.world().get_entity(thistle).is_err(), "the slash should cut the thistle down");
+    assert_eq!(app.world().resource::<GameData>().combo, 2);
+    assert_eq!(count::<With<SeedOrb>>(&mut app), seeds, "thistles don't scatter seeds");
+}
+
+#[test]
+fn thistles_use_their_own_health_scaling() {
+    let mut app = test_app();
+    let level = {
+        let mut level_data = app.world_mut().resource_mut::<LevelData>();
+        level_data.current_level = 6;
+        level_data.levels[5].enemy_scaling.clone()
+    };
+    assert_ne!(level.thistle_health_multiplier, level.health_multiplier);
+
+    // Full health by the thistle multiplier, so no health bar shows
+    let health = (DandelionSize::Small.base_health() as f32 * level.thistle_health_multiplier).ceil() as u32;
+    spawn_thistle(&mut app, DandelionSize::Small, health, Vec2::ZERO);
+    run_frames(&mut app, 2);
+    assert_eq!(count::<With<HealthBar>>(&mut app), 0);
+    assert_eq!(level.health_multiplier_for(Species::Thistle), level.thistle_health_multiplier);
+    assert_eq!(level.health_multiplier_for(Species::Clover), level.health_multiplier);
+}
+
+/// Give the current level roots that regrow after a second
+fn enable_roots(app: &mut App) {
+    let mut level_data = app.world_mut().resource_mut::<LevelData>();
+    let index = (level_data.current_level - 1) as usize;
+    level_data.levels[index].root_regrow_time = Some(std::time::Duration::from_secs(1));
+}
+
+#[test]
+fn killed_dandelion_regrows_from_its_root() {
+    let mut app = test_app();
+    spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(-200.0, 0.0));
+    click(&mut app, Vec2::new(-200.0, 0.0));
+    assert_eq!(count::<With<Root>>(&mut app), 0, "levels leave no roots unless they ask for them");
+
+    enable_roots(&mut app);
+    spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+    click(&mut app, Vec2::ZERO);
+    app.update();
+    assert_eq!(count::<With<Root>>(&mut app), 1);
+    assert_eq!(app.world().resource::<LevelSession>().roots_left, 1);
+    assert!(app.world().resource::<DandelionAreaTracker>().root_area > 0.0);
+
+    run_frames(&mut app, 70);
+    assert_eq!(count::<With<Root>>(&mut app), 0);
+    assert_eq!(app.world().resource::<LevelSession>().roots_regrown, 1);
+    assert_eq!(app.world().resource::<DandelionAreaTracker>().root_area, 0.0);
+    let regrown = app
+        .world_mut()
+        .query::<(&Transform, &Dandelion)>()
+        .iter(app.world())
+        .any(|(transform, dandelion)| transform.translation.truncate() == Vec2::ZERO && dandelion.size == DandelionSize::Tiny);
+    assert!(regrown, "a tiny dandelion should grow back where the root was");
+}
+
+#[test]
+fn hitting_a_root_again_digs_it_up() {
+    let mut app = test_app();
+    enable_roots(&mut app);
+    spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+    click(&mut app, Vec2::ZERO);
+    app.update();
+
+    click(&mut app, Vec2::ZERO);
+    assert_eq!(count::<With<Root>>(&mut app), 0);
+    run_frames(&mut app, 70);
+    let session = app.world().resource::<LevelSession>();
+    assert_eq!((session.roots_left, session.roots_cleared, session.roots_regrown), (1, 1, 0));
+}
+
+#[test]
+fn fire_burns_roots() {
+    let mut app = test_app();
+    enable_roots(&mut app);
+    spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+    click(&mut app, Vec2::ZERO);
+    app.update();
+    assert_eq!(count::<With<Root>>(&mut app), 1);
+
+    app.world_mut().spawn((Transform::default(), Sprite::default(), FireIgnition::default()));
+    run_frames(&mut app, 3);
+    assert_eq!(count::<With<Root>>(&mut app), 0);
+    let session = app.world().resource::<LevelSession>();
+    assert_eq!((session.roots_left, session.roots_cleared), (1, 1));
+
+    // Dandelions burned to death leave nothing behind at all
+    let burned = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::new(300.0, 0.0));
+    app.world_mut()
+        .spawn((Transform::from_xyz(300.0, 0.0, 12.0), Sprite::default(), FireIgnition::default()));
+    run_frames(&mut app, 3);
+    assert!(app.world().get_entity(burned).is_err());
+    assert_eq!(app.world().resource::<LevelSession>().roots_left, 1);
+}
+
+#[test]
+fn killing_a_flower_scatters_no_seeds() {
+    let mut app = test_app();
+    let flower = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+    app.world_mut().entity_mut(flower).insert(Flower::default());
+
+    click(&mut app, Vec2::ZERO);
+    assert!(app.world().get_entity(flower).is_err());
+    assert_eq!(app.world().resource::<SeenEvents>().deaths.len(), 1);
+    assert_eq!(count::<With<SeedOrb>>(&mut app), 0, "flowers haven't gone to seed yet");
+}
+
+#[test]
+fn killing_a_puffball_releases_its_seeds() {
+    let mut app = test_app();
+    let puffball = spawn_dandelion(&mut app, DandelionSize::Small, Vec2::ZERO);
+    app.world_mut().entity_mut(puffball).insert(Puffball::default());
+
+    click(&mut app, Vec2::ZERO);
+    click(&mut app, Vec2::ZERO);
+    assert!(app.world().get_entity(puffball).is_err());
+    assert_eq!(count::<With<SeedOrb>>(&mut app), DandelionSize::Small.spawn_count() as usize);
+}
+
+#[test]
+fn flowers_go_to_seed_and_the_wind_disperses_them() {
+    let mut app = test_app();
+    let dandelion = spawn_dandelion(&mut app, DandelionSize::Tiny, Vec2::ZERO);
+    app.world_mut().entity_mut(dandelion).insert(Flower::default());
+
+    let mut frames = 0;
+    while app.world().get::<Puffball>(dandelion).is_none() {
+        app.update();
+        frames += 1;
+        assert!(frames < 600, "the flower should turn into a puffball");
+    }
+    assert!(app.world().get::<Flower>(dandelion).is_none());
+    assert_eq!(count::<With<SeedOrb>>(&mut app), 0);
+
+    while app.world().get::<Puffball>(dandelion).is_some() {
+        app.update();
+        frames += 1;
+        assert!(frames < 1200, "the puffball should be dispersed by the wind");
+    }
+    assert_eq!(count::<With<SeedOrb>>(&mut app), DandelionSize::Tiny.spawn_count() as usize);
+    assert!(app.world().get::<Flower>(dandelion).is_some(), "it flowers again once its seeds are gone");
+}
+
+/// Positions of the dandelions grown from the seeds of a Huge dandelion killed at the origin
+fn seedlings_of_huge_dandelion(app: &mut App) -> Vec<Vec2> {
+    spawn_dandelion(app, DandelionSize::Huge, Vec2::ZERO);
+    for _ in 0..DandelionSize::Huge.base_health() {
+        click(app, Vec2::ZERO);
+    }
+    assert_eq!(count::<With<SeedOrb>>(app), DandelionSize::Huge.spawn_count() as usize);
+    run_frames(app, 40);
+    assert_eq!(count::<With<SeedOrb>>(app), 0, "every seed should have landed");
+    app.world_mut()
+        .query_filtered::<&Transform, With<Dandelion>>()
+        .iter(app.world())
+        .map(|transform| transform.translation.truncate())
+        .collect()
+}
+
+#[test]
+fn wind_blows_seeds_downwind() {
+    let mut app = test_app();
+    assert_eq!(app.world().resource::<Wind>().strength, 0.0, "the first level has still air");
+    app.insert_resource(Wind::new(1.0));
+    assert_eq!(app.world().resource::<Wind>().compass_point(), "E");
+
+    let seedlings = seedlings_of_huge_dandelion(&mut app);
+    assert!(!seedlings.is_empty());
+    let mean_x = seedlings.iter().map(|position| position.x).sum::<f32>() / seedlings.len() as f32;
+    assert!(mean_x > 40.0, "seeds should drift east with the wind, landed at {:?}", seedlings);
+}
@@ -0,0 +1,97 @@
+//! Touch input turned into per-finger pointers, driven by synthetic `TouchInput` events.
+
+use bevy::input::InputPlugin;
+use bevy::input::touch::{TouchInput, TouchPhase};
+use bevy::prelude::*;
+
+use kill_all_dandelions::input::{Pointer, PointerId, touch_pointers};
+
+fn touch_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin));
+    app
+}
+
+/// Send touch events and return the pointers seen on the following frame, in screen coordinates
+fn touch_frame(app: &mut App, events: &[(u64, TouchPhase, Vec2)]) -> Vec<Pointer> {
+    for &(id, phase, position) in events {
+        app.world_mut().send_event(TouchInput {
+            phase,
+            position,
+            window: Entity::PLACEHOLDER,
+            force: None,
+            id,
+        });
+    }
+    app.update();
+    touch_pointers(app.world().resource::<Touches>(), Some)
+}
+
+#[test]
+fn every_new_touch_becomes_a_pointer() {
+    let mut app = touch_app();
+
+    let pointers = touch_frame(
+        &mut app,
+        &[
+            (1, TouchPhase::Started, Vec2::new(10.0, 20.0)),
+            (2, TouchPhase::Started, Vec2::new(300.0, 40.0)),
+        ],
+    );
+
+    assert_eq!(
+        pointers,
+        vec![
+            Pointer {
+                id: PointerId::Touch(1),
+                position: Some(Vec2::new(10.0, 20.0)),
+                just_pressed: true,
+                just_released: false,
+            },
+            Pointer {
+                id: PointerId::Touch(2),
+                position: Some(Vec2::new(300.0, 40.0)),
+                just_pressed: true,
+                just_released: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn touches_are_tracked_until_they_are_lifted() {
+    let mut app = touch_app();
+    touch_frame(
+        &mut app,
+        &[
+            (1, TouchPhase::Started, Vec2::new(10.0, 20.0)),
+            (2, TouchPhase::Started, Vec2::new(300.0, 40.0)),
+        ],
+    );
+
+    let pointers = touch_frame(
+        &mut app,
+        &[(1, TouchPhase::Moved, Vec2::new(60.0, 20.0)), (2, TouchPhase::Ended, Vec2::new(300.0, 40.0))],
+    );
+    assert_eq!(
+        pointers,
+        vec![
+            Pointer {
+                id: PointerId::Touch(1),
+                position: Some(Vec2::new(60.0, 20.0)),
+                just_pressed: false,
+                just_released: false,
+            },
+            Pointer {
+                id: PointerId::Touch(2),
+                position: Some(Vec2::new(300.0, 40.0)),
+                just_pressed: false,
+                just_released: true,
+            },
+        ]
+    );
+
+    // Lifted touches are gone once their release has been seen
+    let pointers = touch_frame(&mut app, &[]);
+    assert_eq!(pointers.iter().map(|pointer| pointer.id).collect::<Vec<_>>(), vec![PointerId::Touch(1)]);
+}
@@ -0,0 +1,83 @@
+//! Recorded runs played back headlessly alongside the progress save.
+//! The save and replays are written relative to the working directory, so this binary runs in a scratch directory.
+
+use bevy::prelude::*;
+use std::time::Duration;
+
+use kill_all_dandelions::headless::HeadlessGameplayPlugin;
+use kill_all_dandelions::levels::{LevelCompleteEvent, LevelData};
+use kill_all_dandelions::powerups::{PowerupUpgrades, Upgrade};
+use kill_all_dandelions::replay::{ReplayPlugin, load_replay_arg};
+use kill_all_dandelions::save::{SavePlugin, load_level_data, save_level_data};
+
+fn run_frames(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+fn complete_level(app: &mut App) {
+    app.world_mut().send_event(LevelCompleteEvent {
+        level_id: 1,
+        completion_time: Duration::from_secs(30),
+        final_score: 1000,
+        stars_earned: 1,
+    });
+    run_frames(app, 2);
+}
+
+#[test]
+fn replay_leaves_saved_upgrades_alone() {
+    let scratch = std::env::temp_dir().join(format!("kill_all_dandelions_replay_{}", std::process::id()));
+    std::fs::create_dir_all(&scratch).unwrap();
+    std::env::set_current_dir(&scratch).unwrap();
+
+    // Record a run without any upgrades
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, HeadlessGameplayPlugin { seed: Some(1) }, ReplayPlugin { replay: None }));
+    app.finish();
+    app.cleanup();
+    run_frames(&mut app, 10);
+    complete_level(&mut app);
+
+    // The player has since bought an upgrade
+    let mut upgrades = PowerupUpgrades::default();
+    assert!(upgrades.buy(Upgrade::RabbitSpeed, 10));
+    save_level_data(&LevelData {
+        upgrades: upgrades.clone(),
+        ..LevelData::default()
+    });
+
+    let args = ["--replay".to_string(), "save/replays/level_1.ron".to_string()];
+    let replay = load_replay_arg(&args).unwrap().expect("the run should have been recorded");
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        HeadlessGameplayPlugin { seed: Some(1) },
+        SavePlugin,
+        ReplayPlugin { replay: Some(replay) },
+    ));
+    app.finish();
+    app.cleanup();
+    run_frames(&mut app, 5);
+    assert_eq!(
+        app.world().resource::<LevelData>().upgrades,
+        PowerupUpgrades::default(),
+        "the replay should play with the upgrades it was recorded with"
+    );
+
+    // Completing the replayed level would normally save progress
+    complete_level(&mut app);
+
+    assert_eq!(load_level_data().upgrades, upgrades, "the saved upgrades should be untouched");
+    let level_data = app.world().resource::<LevelData>();
+    assert!(!level_data.level_progress[0].completed, "the replayed completion should not count as progress");
+    assert_eq!(
+        app.world().resource::<LevelData>().upgrades,
+        upgrades,
+        "the player's upgrades should be back once the replay is over"
+    );
+
+    std::fs::remove_dir_all(&scratch).ok();
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use kill_all_dandelions::spatial_grid::SpatialGrid;
+
+fn entities(grid: &SpatialGrid, center: Vec2, radius: f32) -> Vec<Entity> {
+    let mut found: Vec<Entity> = grid.query_radius(center, radius).map(|(entity, _)| entity).collect();
+    found.sort();
+    found
+}
+
+#[test]
+fn query_radius_matches_a_linear_scan() {
+    let mut grid = SpatialGrid::default();
+    let points: Vec<(Entity, Vec2)> = (0..400)
+        .map(|i| {
+            // Deterministic scatter across negative and positive coordinates and cell borders
+            let position = Vec2::new(((i * 37) % 640) as f32 - 320.0, ((i * 91) % 480) as f32 - 240.0);
+            (Entity::from_raw(i), position)
+        })
+        .collect();
+    for &(entity, position) in &points {
+        grid.insert(entity, position);
+    }
+
+    for (center, radius) in [
+        (Vec2::ZERO, 50.0),
+        (Vec2::new(-300.0, 200.0), 120.0),
+        (Vec2::new(100.0, -100.0), 0.0),
+        (Vec2::ZERO, 1000.0),
+    ] {
+        let mut expected: Vec<Entity> = points
+            .iter()
+            .filter(|(_, position)| position.distance(center) <= radius)
+            .map(|(entity, _)| *entity)
+            .collect();
+        expected.sort();
+        assert_eq!(entities(&grid, center, radius), expected, "center {center}, radius {radius}");
+    }
+}
+
+#[test]
+fn queries_outside_occupied_cells_find_nothing() {
+    let mut grid = SpatialGrid::default();
+    grid.insert(Entity::from_raw(1), Vec2::new(10.0, 10.0));
+
+    assert!(entities(&grid, Vec2::new(5000.0, 10.0), 100.0).is_empty());
+    assert!(grid.query_rect(Rect::new(-900.0, 300.0, -500.0, 900.0)).next().is_none());
+}
+
+#[test]
+fn clear_empties_the_grid() {
+    let mut grid = SpatialGrid::default();
+    grid.insert(Entity::from_raw(1), Vec2::ZERO);
+    grid.insert(Entity::from_raw(2), Vec2::new(-250.0, 40.0));
+    assert_eq!(grid.len(), 2);
+    assert!(grid.bounds().is_some_and(|bounds| bounds.contains(Vec2::new(-250.0, 40.0))));
+
+    grid.clear();
+
+    assert!(grid.is_empty());
+    assert!(grid.bounds().is_none());
+    assert!(entities(&grid, Vec2::ZERO, 1000.0).is_empty());
+}